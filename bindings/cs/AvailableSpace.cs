@@ -0,0 +1,29 @@
+namespace Taffy;
+
+public enum AvailableSpaceKind
+{
+    MaxContent,
+    MinContent,
+    Definite,
+}
+
+// The space a layout is computed in, along one axis. Numbers convert to a
+// definite size, and the default is max-content.
+public readonly record struct AvailableSpace(AvailableSpaceKind Kind, float Value = 0)
+{
+    public static readonly AvailableSpace MaxContent = new(AvailableSpaceKind.MaxContent);
+
+    public static readonly AvailableSpace MinContent = new(AvailableSpaceKind.MinContent);
+
+    public static AvailableSpace Definite(float value) => new(AvailableSpaceKind.Definite, value);
+
+    public static implicit operator AvailableSpace(float value) => Definite(value);
+
+    internal Native.AvailableSpace ToNative() =>
+        Kind switch
+        {
+            AvailableSpaceKind.Definite => new Native.AvailableSpace.Definite(Value),
+            AvailableSpaceKind.MinContent => new Native.AvailableSpace.MinContent(),
+            _ => new Native.AvailableSpace.MaxContent(),
+        };
+}
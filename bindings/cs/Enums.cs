@@ -0,0 +1,72 @@
+namespace Taffy;
+
+// Declared in the order of the generated enums, so they convert with a cast.
+
+public enum Display
+{
+    Block,
+    Flex,
+    Grid,
+    None,
+}
+
+public enum Overflow
+{
+    Visible,
+    Clip,
+    Hidden,
+    Scroll,
+}
+
+public enum Position
+{
+    Relative,
+    Absolute,
+}
+
+public enum AlignItems
+{
+    Start,
+    End,
+    FlexStart,
+    FlexEnd,
+    Center,
+    Baseline,
+    Stretch,
+}
+
+public enum AlignContent
+{
+    Start,
+    End,
+    FlexStart,
+    FlexEnd,
+    Center,
+    Stretch,
+    SpaceBetween,
+    SpaceEvenly,
+    SpaceAround,
+}
+
+public enum FlexDirection
+{
+    Row,
+    Column,
+    RowReverse,
+    ColumnReverse,
+}
+
+public enum FlexWrap
+{
+    NoWrap,
+    Wrap,
+    WrapReverse,
+}
+
+public enum GridAutoFlow
+{
+    Row,
+    Column,
+    RowDense,
+    ColumnDense,
+}
@@ -0,0 +1,73 @@
+namespace Taffy;
+
+public readonly record struct Point(float X, float Y);
+
+public readonly record struct Size<T>(T Width, T Height);
+
+// Sides in CSS order.
+public readonly record struct Edges<T>(T Top, T Right, T Bottom, T Left);
+
+public static class Size
+{
+    public static Size<T> Both<T>(T value) => new(value, value);
+}
+
+public static class Edges
+{
+    public static Edges<T> All<T>(T value) => new(value, value, value, value);
+
+    public static Edges<T> Symmetric<T>(T vertical, T horizontal) =>
+        new(vertical, horizontal, vertical, horizontal);
+}
+
+internal static class Geometry
+{
+    public static Size<Length> ToLengths(this Native.SizeDimension size) =>
+        new(Length.From(size.width), Length.From(size.height));
+
+    public static Size<Length> ToLengths(this Native.SizeLengthPercentage size) =>
+        new(Length.From(size.width), Length.From(size.height));
+
+    public static Edges<Length> ToLengths(this Native.RectLengthPercentage rect) =>
+        new(
+            Length.From(rect.top),
+            Length.From(rect.right),
+            Length.From(rect.bottom),
+            Length.From(rect.left)
+        );
+
+    public static Edges<Length> ToLengths(this Native.RectLengthPercentageAuto rect) =>
+        new(
+            Length.From(rect.top),
+            Length.From(rect.right),
+            Length.From(rect.bottom),
+            Length.From(rect.left)
+        );
+
+    public static Native.SizeDimension ToDimensions(this Size<Length> size) =>
+        new(size.Width.ToDimension(), size.Height.ToDimension());
+
+    public static Native.SizeLengthPercentage ToLengthPercentages(this Size<Length> size) =>
+        new(size.Width.ToLengthPercentage(), size.Height.ToLengthPercentage());
+
+    public static Native.RectLengthPercentage ToLengthPercentages(this Edges<Length> edges) =>
+        new(
+            top: edges.Top.ToLengthPercentage(),
+            bottom: edges.Bottom.ToLengthPercentage(),
+            left: edges.Left.ToLengthPercentage(),
+            right: edges.Right.ToLengthPercentage()
+        );
+
+    public static Native.RectLengthPercentageAuto ToLengthPercentageAutos(this Edges<Length> edges) =>
+        new(
+            top: edges.Top.ToLengthPercentageAuto(),
+            bottom: edges.Bottom.ToLengthPercentageAuto(),
+            left: edges.Left.ToLengthPercentageAuto(),
+            right: edges.Right.ToLengthPercentageAuto()
+        );
+
+    public static Size<float> ToSize(this Native.SizeFloat size) => new(size.width, size.height);
+
+    public static Edges<float> ToEdges(this Native.RectFloat rect) =>
+        new(rect.top, rect.right, rect.bottom, rect.left);
+}
@@ -0,0 +1,23 @@
+namespace Taffy;
+
+public sealed record Layout(
+    uint Order,
+    Point Location,
+    Size<float> Size,
+    Size<float> ContentSize,
+    Size<float> ScrollbarSize,
+    Edges<float> Border,
+    Edges<float> Padding
+)
+{
+    internal static Layout From(Native.Layout layout) =>
+        new(
+            layout.order,
+            new Point(layout.location.x, layout.location.y),
+            layout.size.ToSize(),
+            layout.contentSize.ToSize(),
+            layout.scrollbarSize.ToSize(),
+            layout.border.ToEdges(),
+            layout.padding.ToEdges()
+        );
+}
@@ -0,0 +1,101 @@
+using System;
+
+namespace Taffy;
+
+public enum LengthUnit
+{
+    Px,
+    Percent,
+    Auto,
+}
+
+// A length in pixels, a percentage (50 is 50%) or auto. Numbers convert to
+// pixels, and 10.Px() or 50.Percent() read like CSS.
+public readonly record struct Length(float Value, LengthUnit Unit)
+{
+    public static readonly Length Auto = new(0, LengthUnit.Auto);
+
+    public static Length Px(float value) => new(value, LengthUnit.Px);
+
+    public static Length Percent(float value) => new(value, LengthUnit.Percent);
+
+    public static implicit operator Length(float value) => Px(value);
+
+    public static Length operator -(Length length) => length * -1;
+
+    public static Length operator *(Length length, float factor) =>
+        length.Unit == LengthUnit.Auto ? length : length with { Value = length.Value * factor };
+
+    public static Length operator *(float factor, Length length) => length * factor;
+
+    public override string ToString() =>
+        Unit switch
+        {
+            LengthUnit.Px => $"{Value}px",
+            LengthUnit.Percent => $"{Value}%",
+            _ => "auto",
+        };
+
+    internal Native.Dimension ToDimension() =>
+        Unit switch
+        {
+            LengthUnit.Px => new Native.Dimension.Length(Value),
+            LengthUnit.Percent => new Native.Dimension.Percent(Value / 100),
+            _ => new Native.Dimension.Auto(),
+        };
+
+    internal Native.LengthPercentage ToLengthPercentage() =>
+        Unit switch
+        {
+            LengthUnit.Px => new Native.LengthPercentage.Length(Value),
+            LengthUnit.Percent => new Native.LengthPercentage.Percent(Value / 100),
+            _ => throw new ArgumentException("auto isn't allowed for this property"),
+        };
+
+    internal Native.LengthPercentageAuto ToLengthPercentageAuto() =>
+        Unit switch
+        {
+            LengthUnit.Px => new Native.LengthPercentageAuto.Length(Value),
+            LengthUnit.Percent => new Native.LengthPercentageAuto.Percent(Value / 100),
+            _ => new Native.LengthPercentageAuto.Auto(),
+        };
+
+    internal static Length From(Native.Dimension value) =>
+        value switch
+        {
+            Native.Dimension.Length length => Px(length.value),
+            Native.Dimension.Percent percent => Percent(percent.value * 100),
+            _ => Auto,
+        };
+
+    internal static Length From(Native.LengthPercentage value) =>
+        value switch
+        {
+            Native.LengthPercentage.Length length => Px(length.value),
+            Native.LengthPercentage.Percent percent => Percent(percent.value * 100),
+            _ => throw new ArgumentOutOfRangeException(nameof(value)),
+        };
+
+    internal static Length From(Native.LengthPercentageAuto value) =>
+        value switch
+        {
+            Native.LengthPercentageAuto.Length length => Px(length.value),
+            Native.LengthPercentageAuto.Percent percent => Percent(percent.value * 100),
+            _ => Auto,
+        };
+}
+
+public static class LengthExtensions
+{
+    public static Length Px(this int value) => Length.Px(value);
+
+    public static Length Px(this float value) => Length.Px(value);
+
+    public static Length Px(this double value) => Length.Px((float)value);
+
+    public static Length Percent(this int value) => Length.Percent(value);
+
+    public static Length Percent(this float value) => Length.Percent(value);
+
+    public static Length Percent(this double value) => Length.Percent((float)value);
+}
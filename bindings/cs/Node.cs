@@ -0,0 +1,70 @@
+using System.Collections.Generic;
+using System.Linq;
+
+namespace Taffy;
+
+// A node of a TaffyTree. Nodes compare equal when they are the same node of
+// the same tree.
+public sealed record Node
+{
+    internal Node(TaffyTree tree, ulong id)
+    {
+        Tree = tree;
+        Id = id;
+    }
+
+    public TaffyTree Tree { get; }
+
+    public ulong Id { get; }
+
+    public Node? Parent =>
+        Ffi.Call(() => Tree.Inner.GetParent(Id)) is { } parent ? new Node(Tree, parent) : null;
+
+    public IReadOnlyList<Node> Children =>
+        Ffi.Call(() => Tree.Inner.GetChildren(Id)).Select(id => new Node(Tree, id)).ToList();
+
+    public Layout Layout => Layout.From(Ffi.Call(() => Tree.Inner.GetLayout(Id)));
+
+    // Reading returns a copy of the node's style, which the caller disposes.
+    public Style Style
+    {
+        get => new(Ffi.Call(() => Tree.Inner.GetStyle(Id)));
+        set => Ffi.Call(() => Tree.Inner.SetStyle(Id, value.Inner));
+    }
+
+    public bool IsDirty => Ffi.Call(() => Tree.Inner.IsDirty(Id));
+
+    public void MarkDirty() => Ffi.Call(() => Tree.Inner.MarkDirty(Id));
+
+    public void AddChild(Node child) => InsertChild(Children.Count, child);
+
+    public void InsertChild(int index, Node child)
+    {
+        var id = Tree.Id(child);
+        Ffi.Call(() => Tree.Inner.InsertChildAtIndex(Id, (ulong)index, id));
+    }
+
+    public void RemoveChild(Node child)
+    {
+        var id = Tree.Id(child);
+        Ffi.Call(() => Tree.Inner.RemoveChild(Id, id));
+    }
+
+    public void SetChildren(IEnumerable<Node> children)
+    {
+        var ids = Tree.Ids(children);
+        Ffi.Call(() => Tree.Inner.SetChildren(Id, ids));
+    }
+
+    // The nodes under this one, depth first and in child order. The whole
+    // subtree is read in a single call, when this is called.
+    public IEnumerable<Node> Descendants() =>
+        Ffi.Call(() => Tree.Inner.GetLayouts(Id))
+            .Skip(1)
+            .Select(entry => new Node(Tree, entry.node))
+            .ToList();
+
+    public IEnumerable<Node> DescendantsAndSelf() => Descendants().Prepend(this);
+
+    public override string ToString() => $"Node({Id})";
+}
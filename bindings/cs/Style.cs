@@ -0,0 +1,188 @@
+using System;
+
+namespace Taffy;
+
+// A style with a property for every numeric and keyword value, so it can be
+// built with an object initializer:
+//
+//     using var style = new Style { Display = Display.Flex, Size = new(100.Px(), 50.Percent()) };
+//
+// Grid tracks and placements are set through CSS, with FromCss or ApplyCss.
+public sealed class Style : IDisposable
+{
+    public Style()
+        : this(new Native.Style()) { }
+
+    internal Style(Native.Style inner)
+    {
+        Inner = inner;
+    }
+
+    internal Native.Style Inner { get; }
+
+    public static Style FromCss(string css) => new(Ffi.Call(() => Native.Style.FromCss(css)));
+
+    public void ApplyCss(string css) => Ffi.Call(() => Inner.ApplyCss(css));
+
+    public string ToCss() => Ffi.Call(() => Inner.ToCss());
+
+    public override string ToString() => ToCss();
+
+    public void Dispose() => Inner.Dispose();
+
+    public Display Display
+    {
+        get => (Display)Ffi.Call(() => Inner.GetDisplay());
+        set => Ffi.Call(() => Inner.SetDisplay((Native.Display)value));
+    }
+
+    public Overflow OverflowX
+    {
+        get => (Overflow)Ffi.Call(() => Inner.GetOverflow()).x;
+        set => Ffi.Call(() => Inner.SetOverflow(Inner.GetOverflow() with { x = (Native.Overflow)value }));
+    }
+
+    public Overflow OverflowY
+    {
+        get => (Overflow)Ffi.Call(() => Inner.GetOverflow()).y;
+        set => Ffi.Call(() => Inner.SetOverflow(Inner.GetOverflow() with { y = (Native.Overflow)value }));
+    }
+
+    public float ScrollbarWidth
+    {
+        get => Ffi.Call(() => Inner.GetScrollbarWidth());
+        set => Ffi.Call(() => Inner.SetScrollbarWidth(value));
+    }
+
+    public Position Position
+    {
+        get => (Position)Ffi.Call(() => Inner.GetPosition());
+        set => Ffi.Call(() => Inner.SetPosition((Native.Position)value));
+    }
+
+    public Edges<Length> Inset
+    {
+        get => Ffi.Call(() => Inner.GetInset()).ToLengths();
+        set => Ffi.Call(() => Inner.SetInset(value.ToLengthPercentageAutos()));
+    }
+
+    public Size<Length> Size
+    {
+        get => Ffi.Call(() => Inner.GetSize()).ToLengths();
+        set => Ffi.Call(() => Inner.SetSize(value.ToDimensions()));
+    }
+
+    public Size<Length> MinSize
+    {
+        get => Ffi.Call(() => Inner.GetMinSize()).ToLengths();
+        set => Ffi.Call(() => Inner.SetMinSize(value.ToDimensions()));
+    }
+
+    public Size<Length> MaxSize
+    {
+        get => Ffi.Call(() => Inner.GetMaxSize()).ToLengths();
+        set => Ffi.Call(() => Inner.SetMaxSize(value.ToDimensions()));
+    }
+
+    public float? AspectRatio
+    {
+        get => Ffi.Call(() => Inner.GetAspectRatio());
+        set => Ffi.Call(() => Inner.SetAspectRatio(value));
+    }
+
+    public Edges<Length> Margin
+    {
+        get => Ffi.Call(() => Inner.GetMargin()).ToLengths();
+        set => Ffi.Call(() => Inner.SetMargin(value.ToLengthPercentageAutos()));
+    }
+
+    public Edges<Length> Padding
+    {
+        get => Ffi.Call(() => Inner.GetPadding()).ToLengths();
+        set => Ffi.Call(() => Inner.SetPadding(value.ToLengthPercentages()));
+    }
+
+    public Edges<Length> Border
+    {
+        get => Ffi.Call(() => Inner.GetBorder()).ToLengths();
+        set => Ffi.Call(() => Inner.SetBorder(value.ToLengthPercentages()));
+    }
+
+    public AlignItems? AlignItems
+    {
+        get => (AlignItems?)Ffi.Call(() => Inner.GetAlignItems());
+        set => Ffi.Call(() => Inner.SetAlignItems((Native.AlignItems?)value));
+    }
+
+    public AlignItems? AlignSelf
+    {
+        get => (AlignItems?)Ffi.Call(() => Inner.GetAlignSelf());
+        set => Ffi.Call(() => Inner.SetAlignSelf((Native.AlignItems?)value));
+    }
+
+    public AlignItems? JustifyItems
+    {
+        get => (AlignItems?)Ffi.Call(() => Inner.GetJustifyItems());
+        set => Ffi.Call(() => Inner.SetJustifyItems((Native.AlignItems?)value));
+    }
+
+    public AlignItems? JustifySelf
+    {
+        get => (AlignItems?)Ffi.Call(() => Inner.GetJustifySelf());
+        set => Ffi.Call(() => Inner.SetJustifySelf((Native.AlignItems?)value));
+    }
+
+    public AlignContent? AlignContent
+    {
+        get => (AlignContent?)Ffi.Call(() => Inner.GetAlignContent());
+        set => Ffi.Call(() => Inner.SetAlignContent((Native.AlignContent?)value));
+    }
+
+    public AlignContent? JustifyContent
+    {
+        get => (AlignContent?)Ffi.Call(() => Inner.GetJustifyContent());
+        set => Ffi.Call(() => Inner.SetJustifyContent((Native.AlignContent?)value));
+    }
+
+    public Size<Length> Gap
+    {
+        get => Ffi.Call(() => Inner.GetGap()).ToLengths();
+        set => Ffi.Call(() => Inner.SetGap(value.ToLengthPercentages()));
+    }
+
+    public FlexDirection FlexDirection
+    {
+        get => (FlexDirection)Ffi.Call(() => Inner.GetFlexDirection());
+        set => Ffi.Call(() => Inner.SetFlexDirection((Native.FlexDirection)value));
+    }
+
+    public FlexWrap FlexWrap
+    {
+        get => (FlexWrap)Ffi.Call(() => Inner.GetFlexWrap());
+        set => Ffi.Call(() => Inner.SetFlexWrap((Native.FlexWrap)value));
+    }
+
+    public Length FlexBasis
+    {
+        get => Length.From(Ffi.Call(() => Inner.GetFlexBasis()));
+        set => Ffi.Call(() => Inner.SetFlexBasis(value.ToDimension()));
+    }
+
+    public float FlexGrow
+    {
+        get => Ffi.Call(() => Inner.GetFlexGrow());
+        set => Ffi.Call(() => Inner.SetFlexGrow(value));
+    }
+
+    public float FlexShrink
+    {
+        get => Ffi.Call(() => Inner.GetFlexShrink());
+        set => Ffi.Call(() => Inner.SetFlexShrink(value));
+    }
+
+    public GridAutoFlow GridAutoFlow
+    {
+        get => (GridAutoFlow)Ffi.Call(() => Inner.GetGridAutoFlow());
+        set => Ffi.Call(() => Inner.SetGridAutoFlow((Native.GridAutoFlow)value));
+    }
+}
@@ -0,0 +1,76 @@
+using System;
+using Xunit;
+
+namespace Taffy.Tests;
+
+public class StyleTests
+{
+    [Fact]
+    public void ObjectInitializersSetProperties()
+    {
+        using var style = new Style
+        {
+            Display = Display.Grid,
+            Size = new(100.Px(), 50.Percent()),
+            Margin = new(0, Length.Auto, 0, Length.Auto),
+            Padding = Edges.Symmetric(4.Px(), 8.Px()),
+            FlexGrow = 2,
+            AlignItems = AlignItems.Center,
+            AspectRatio = 1.5f,
+        };
+
+        Assert.Equal(Display.Grid, style.Display);
+        Assert.Equal(new Size<Length>(100.Px(), 50.Percent()), style.Size);
+        Assert.Equal(new Edges<Length>(0, Length.Auto, 0, Length.Auto), style.Margin);
+        Assert.Equal(new Edges<Length>(4.Px(), 8.Px(), 4.Px(), 8.Px()), style.Padding);
+        Assert.Equal(2, style.FlexGrow);
+        Assert.Equal(AlignItems.Center, style.AlignItems);
+        Assert.Equal(1.5f, style.AspectRatio);
+        Assert.Null(style.JustifyItems);
+    }
+
+    [Fact]
+    public void LengthsReadLikeCss()
+    {
+        Assert.Equal(new Length(10, LengthUnit.Px), 10.Px());
+        Assert.Equal(new Length(50, LengthUnit.Percent), 50.Percent());
+        Assert.Equal(12.Px(), (Length)12f);
+        Assert.Equal((-10).Px(), -10.Px());
+        Assert.Equal(30.Percent(), 3 * 10.Percent());
+        Assert.Equal(Length.Auto, Length.Auto * 2);
+        Assert.Equal("10px", 10.Px().ToString());
+        Assert.Equal("50%", 50.Percent().ToString());
+        Assert.Equal("auto", Length.Auto.ToString());
+    }
+
+    [Fact]
+    public void PercentagesMatchCss()
+    {
+        using var style = Style.FromCss("width: 25%; height: 10px");
+        Assert.Equal(new Size<Length>(25.Percent(), 10.Px()), style.Size);
+
+        style.Size = new(75.Percent(), Length.Auto);
+        Assert.Equal("width: 75%;", style.ToCss());
+    }
+
+    [Fact]
+    public void InvalidValuesThrow()
+    {
+        using var style = new Style();
+        var error = Assert.Throws<TaffyException>(() => style.FlexGrow = float.NaN);
+        Assert.Equal(TaffyErrorKind.InvalidStyleValue, error.Kind);
+        Assert.Throws<ArgumentException>(() => style.Padding = Edges.All(Length.Auto));
+
+        error = Assert.Throws<TaffyException>(() => Style.FromCss("display: sideways"));
+        Assert.Equal(TaffyErrorKind.InvalidCss, error.Kind);
+        Assert.StartsWith("invalid CSS at 1:10", error.Message);
+    }
+
+    [Fact]
+    public void DisposedStylesCantBeUsed()
+    {
+        var style = new Style();
+        style.Dispose();
+        Assert.Throws<ObjectDisposedException>(() => style.Display);
+    }
+}
@@ -0,0 +1,89 @@
+using System;
+using System.Linq;
+using Xunit;
+
+namespace Taffy.Tests;
+
+public class TreeTests
+{
+    [Fact]
+    public void LaysOutATree()
+    {
+        using var tree = new TaffyTree();
+        using var leafStyle = new Style { Size = new(50.Px(), 20.Px()) };
+        using var rootStyle = new Style { Display = Display.Flex, Padding = Edges.All(10.Px()) };
+        var first = tree.NewLeaf(leafStyle);
+        var second = tree.NewLeaf(leafStyle);
+        var root = tree.NewNode(rootStyle, first, second);
+
+        tree.ComputeLayout(root);
+
+        Assert.Equal(new Size<float>(120, 40), root.Layout.Size);
+        Assert.Equal(new Point(60, 10), second.Layout.Location);
+        Assert.Equal(new Edges<float>(10, 10, 10, 10), root.Layout.Padding);
+        Assert.False(root.IsDirty);
+    }
+
+    [Fact]
+    public void DescendantsAreDepthFirstInChildOrder()
+    {
+        using var tree = new TaffyTree();
+        using var style = new Style();
+        var grandchild = tree.NewLeaf(style);
+        var first = tree.NewNode(style, grandchild);
+        var second = tree.NewLeaf(style);
+        var root = tree.NewNode(style, first, second);
+
+        Assert.Equal(new[] { first, grandchild, second }, root.Descendants());
+        Assert.Equal(new[] { root, first, grandchild, second }, root.DescendantsAndSelf());
+        Assert.Empty(second.Descendants());
+        Assert.Equal(root, grandchild.Parent?.Parent);
+        Assert.Equal(new[] { first, second }, root.Children);
+    }
+
+    [Fact]
+    public void ChildrenCanBeRearranged()
+    {
+        using var tree = new TaffyTree();
+        var root = tree.NewLeaf();
+        var first = tree.NewLeaf();
+        var second = tree.NewLeaf();
+
+        root.AddChild(second);
+        root.InsertChild(0, first);
+        Assert.Equal(new[] { first, second }, root.Children);
+
+        root.RemoveChild(first);
+        Assert.Equal(new[] { second }, root.Children);
+        Assert.Null(first.Parent);
+
+        root.SetChildren(new[] { first, second }.Reverse());
+        Assert.Equal(new[] { second, first }, root.Descendants());
+    }
+
+    [Fact]
+    public void TreeErrorsThrow()
+    {
+        using var tree = new TaffyTree();
+        using var other = new TaffyTree();
+        using var style = new Style();
+        var child = tree.NewLeaf();
+        var parent = tree.NewNode(style, child);
+
+        var error = Assert.Throws<TaffyException>(() => child.AddChild(parent));
+        Assert.Equal(TaffyErrorKind.CyclicChild, error.Kind);
+        Assert.Throws<ArgumentException>(() => other.NewNode(style, child));
+    }
+
+    [Fact]
+    public void DisposedTreesCantBeUsed()
+    {
+        var tree = new TaffyTree();
+        var node = tree.NewLeaf();
+        Assert.Equal(1ul, tree.Count);
+
+        tree.Dispose();
+        Assert.Throws<ObjectDisposedException>(() => tree.Count);
+        Assert.Throws<ObjectDisposedException>(() => node.Layout);
+    }
+}
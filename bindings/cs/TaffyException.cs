@@ -0,0 +1,75 @@
+using System;
+
+namespace Taffy;
+
+public enum TaffyErrorKind
+{
+    Poison,
+    ChildIndexOutOfBounds,
+    InvalidParentNode,
+    InvalidChildNode,
+    InvalidInputNode,
+    InvalidFontData,
+    InvalidFont,
+    InvalidStyleValue,
+    MeasureFailed,
+    LockContention,
+    InvalidCss,
+    InvalidDocument,
+    NotAChild,
+    DuplicateChild,
+    CyclicChild,
+    Internal,
+}
+
+// Message is the whole message from error_message. The details are on the
+// generated exception in InnerException, where the text that comes with an
+// error is a `reason` field rather than `message`, as in the other bindings.
+public sealed class TaffyException : Exception
+{
+    internal TaffyException(TaffyErrorKind kind, string message, Exception inner)
+        : base(message, inner)
+    {
+        Kind = kind;
+    }
+
+    public TaffyErrorKind Kind { get; }
+}
+
+// The generated exceptions are internal, so every call into the bindings goes
+// through here to rethrow them as a TaffyException.
+internal static class Ffi
+{
+    public static T Call<T>(Func<T> call)
+    {
+        try
+        {
+            return call();
+        }
+        catch (Native.TaffyException e)
+        {
+            throw Wrap(e);
+        }
+    }
+
+    public static void Call(Action call)
+    {
+        try
+        {
+            call();
+        }
+        catch (Native.TaffyException e)
+        {
+            throw Wrap(e);
+        }
+    }
+
+    private static TaffyException Wrap(Native.TaffyException e)
+    {
+        // Each variant is a nested class named after the kind.
+        var kind = Enum.TryParse(e.GetType().Name, out TaffyErrorKind parsed)
+            ? parsed
+            : TaffyErrorKind.Internal;
+        return new TaffyException(kind, Native.TaffyMethods.ErrorMessage(e), e);
+    }
+}
@@ -0,0 +1,72 @@
+using System;
+using System.Collections.Generic;
+using System.Linq;
+
+namespace Taffy;
+
+// Owns the native tree and every node in it; nodes can't be used once the tree
+// is disposed.
+public sealed class TaffyTree : IDisposable
+{
+    public TaffyTree()
+    {
+        Inner = new Native.TaffyTree();
+    }
+
+    public TaffyTree(ulong capacity)
+    {
+        Inner = Native.TaffyTree.WithCapacity(capacity);
+    }
+
+    internal Native.TaffyTree Inner { get; }
+
+    public ulong Count => Ffi.Call(() => Inner.GetTotalNodeCount());
+
+    public Node NewLeaf()
+    {
+        using var style = new Style();
+        return NewLeaf(style);
+    }
+
+    public Node NewLeaf(Style style) => new(this, Ffi.Call(() => Inner.NewLeaf(style.Inner)));
+
+    public Node NewNode(Style style, params Node[] children) =>
+        NewNode(style, (IEnumerable<Node>)children);
+
+    public Node NewNode(Style style, IEnumerable<Node> children)
+    {
+        var ids = Ids(children);
+        return new(this, Ffi.Call(() => Inner.NewWithChildren(style.Inner, ids)));
+    }
+
+    public void Remove(Node node)
+    {
+        var id = Id(node);
+        Ffi.Call(() => Inner.Remove(id));
+    }
+
+    public void Clear() => Ffi.Call(() => Inner.Clear());
+
+    public void ComputeLayout(Node root) =>
+        ComputeLayout(root, AvailableSpace.MaxContent, AvailableSpace.MaxContent);
+
+    public void ComputeLayout(Node root, AvailableSpace width, AvailableSpace height)
+    {
+        var id = Id(root);
+        var space = new Native.SizeAvailableSpace(width.ToNative(), height.ToNative());
+        Ffi.Call(() => Inner.ComputeLayout(id, space));
+    }
+
+    public void Dispose() => Inner.Dispose();
+
+    internal ulong Id(Node node)
+    {
+        if (node.Tree != this)
+        {
+            throw new ArgumentException("the node belongs to another tree", nameof(node));
+        }
+        return node.Id;
+    }
+
+    internal List<ulong> Ids(IEnumerable<Node> nodes) => nodes.Select(Id).ToList();
+}
@@ -0,0 +1,81 @@
+import com.sun.jna.Platform
+
+// JNA's Platform is used below to name the native library and the directory
+// it's bundled under. Keep its version in step with the dependency.
+buildscript {
+    repositories {
+        mavenCentral()
+    }
+    dependencies {
+        classpath("net.java.dev.jna:jna:5.14.0")
+    }
+}
+
+plugins {
+    kotlin("jvm") version "1.9.22"
+    `java-library`
+}
+
+group = "taffy"
+version = "0.1.0"
+
+repositories {
+    mavenCentral()
+}
+
+dependencies {
+    implementation("net.java.dev.jna:jna:5.14.0")
+    testImplementation(kotlin("test"))
+}
+
+kotlin {
+    jvmToolchain(17)
+}
+
+tasks.test {
+    useJUnitPlatform()
+}
+
+val crateDir = rootDir.resolve("../..")
+
+// The generator is built with the crate's debug profile, so that switching
+// its feature on and off doesn't rebuild the release library each time.
+val generateBindings by tasks.registering(Exec::class) {
+    val outDir = layout.buildDirectory.dir("generated/uniffi/kotlin")
+    inputs.files(crateDir.resolve("src/taffy.udl"), crateDir.resolve("uniffi.toml"))
+    outputs.dir(outDir)
+    workingDir = crateDir
+    commandLine(
+        "cargo", "run", "--features", "bindgen", "--bin", "uniffi-bindgen", "--",
+        "generate", "src/taffy.udl",
+        "--language", "kotlin",
+        "--config", "uniffi.toml",
+        "--out-dir", outDir.get().asFile.path,
+    )
+}
+
+// Only the library for the platform running the build is bundled: libtaffy.so,
+// libtaffy.dylib or taffy.dll.
+val nativeLibrary = System.mapLibraryName("taffy")
+
+val buildNativeLibrary by tasks.registering(Exec::class) {
+    inputs.dir(crateDir.resolve("src"))
+    inputs.file(crateDir.resolve("Cargo.toml"))
+    outputs.file(crateDir.resolve("target/release/$nativeLibrary"))
+    workingDir = crateDir
+    commandLine("cargo", "build", "--release", "--lib")
+}
+
+// JNA finds libraries bundled in a jar under a directory named after the
+// platform, such as linux-x86-64 or darwin-aarch64.
+val copyNativeLibrary by tasks.registering(Copy::class) {
+    from(buildNativeLibrary)
+    into(layout.buildDirectory.dir("generated/uniffi/resources/${Platform.RESOURCE_PREFIX}"))
+}
+
+sourceSets {
+    main {
+        kotlin.srcDir(generateBindings)
+        resources.srcDir(copyNativeLibrary.map { it.destinationDir.parentFile })
+    }
+}
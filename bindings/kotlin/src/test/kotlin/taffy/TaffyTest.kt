@@ -0,0 +1,59 @@
+package taffy
+
+import kotlin.test.Test
+import kotlin.test.assertEquals
+import kotlin.test.assertFailsWith
+import kotlin.test.assertTrue
+
+class TaffyTest {
+    private val maxContent = SizeAvailableSpace(AvailableSpace.MaxContent, AvailableSpace.MaxContent)
+
+    @Test
+    fun laysOutAFlexRow() {
+        TaffyTree().use { tree ->
+            val first = Style.fromCss("width: 50px; height: 20px").use { tree.newLeaf(it) }
+            val second = Style().use { style ->
+                style.setSize(SizeDimension(Dimension.Length(30f), Dimension.Length(40f)))
+                tree.newLeaf(style)
+            }
+            val root = Style.fromCss("display: flex; padding: 10px").use {
+                tree.newWithChildren(it, listOf(first, second))
+            }
+
+            tree.computeLayout(root, maxContent)
+
+            assertEquals(SizeFloat(100f, 60f), tree.getLayout(root).size)
+            assertEquals(PointFloat(60f, 10f), tree.getLayout(second).location)
+            assertEquals(listOf(first, second), tree.getChildren(root))
+        }
+    }
+
+    @Test
+    fun readsStylesBack() {
+        Style.fromCss("display: grid; flex-grow: 2").use { style ->
+            assertEquals(Display.GRID, style.getDisplay())
+            assertEquals(2f, style.getFlexGrow())
+            assertEquals("display: grid;\nflex-grow: 2;", style.toCss())
+        }
+    }
+
+    @Test
+    fun throwsTaffyExceptions() {
+        Style().use { style ->
+            val error = assertFailsWith<TaffyException.InvalidStyleValue> { style.setFlexGrow(Float.NaN) }
+            assertEquals("flex-grow", error.property)
+            assertTrue(error.value.isNaN())
+        }
+        val error = assertFailsWith<TaffyException.InvalidCss> { Style.fromCss("display: flex;\n  width: 10qq") }
+        assertEquals(2u, error.line)
+        assertEquals(10u, error.column)
+        assertEquals("invalid CSS at 2:10: unsupported unit 'qq'", errorMessage(error))
+        TaffyTree().use { tree ->
+            val child = Style().use { tree.newLeaf(it) }
+            val parent = Style().use { tree.newWithChildren(it, listOf(child)) }
+            assertFailsWith<TaffyException.CyclicChild> { tree.insertChildAtIndex(child, 0uL, parent) }
+            tree.remove(child)
+            assertFailsWith<TaffyException.InvalidInputNode> { tree.getLayout(child) }
+        }
+    }
+}
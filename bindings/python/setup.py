@@ -0,0 +1,48 @@
+import shutil
+import subprocess
+import tempfile
+from pathlib import Path
+
+from setuptools import setup
+from setuptools.command.build_py import build_py
+from wheel.bdist_wheel import bdist_wheel
+
+CRATE = Path(__file__).resolve().parents[2]
+
+
+class BuildPy(build_py):
+    # Adds the release library and the module uniffi generates for it next to
+    # the wrappers in src/taffy.
+    def run(self):
+        super().run()
+        package = Path(self.build_lib) / "taffy"
+        subprocess.run(["cargo", "build", "--release", "--lib"], cwd=CRATE, check=True)
+        with tempfile.TemporaryDirectory() as out_dir:
+            subprocess.run(
+                [
+                    "cargo", "run", "--features", "bindgen", "--bin", "uniffi-bindgen", "--",
+                    "generate", "src/taffy.udl",
+                    "--language", "python",
+                    "--config", "uniffi.toml",
+                    "--out-dir", out_dir,
+                ],
+                cwd=CRATE,
+                check=True,
+            )
+            shutil.copy(Path(out_dir) / "taffy.py", package / "_ffi.py")
+        shutil.copy(CRATE / "target" / "release" / "libtaffy.so", package)
+
+
+class BdistWheel(bdist_wheel):
+    # The library is loaded through ctypes, so a wheel only depends on the
+    # platform, not on the Python version.
+    def finalize_options(self):
+        super().finalize_options()
+        self.root_is_pure = False
+
+    def get_tag(self):
+        _, _, platform = super().get_tag()
+        return "py3", "none", platform
+
+
+setup(cmdclass={"build_py": BuildPy, "bdist_wheel": BdistWheel})
@@ -0,0 +1,290 @@
+"""Python bindings for the taffy layout engine.
+
+Everything the generated bindings export is available from this package.
+``Style`` and ``TaffyTree`` are extended to take style keyword arguments, trees
+work as context managers and layouts come back as frozen dataclasses.
+"""
+
+from __future__ import annotations
+
+from dataclasses import dataclass
+from typing import Any, Callable, Dict, Iterable, Optional, Tuple
+
+from . import _ffi
+from ._ffi import *  # noqa: F401,F403
+from ._ffi import (
+    AvailableSpace,
+    Dimension,
+    LengthPercentage,
+    LengthPercentageAuto,
+    PointOverflow,
+    RectLengthPercentage,
+    RectLengthPercentageAuto,
+    SizeAvailableSpace,
+    SizeDimension,
+    SizeLengthPercentage,
+)
+
+__all__ = list(dict.fromkeys([*_ffi.__all__, "Point", "Size", "Rect"]))
+
+
+@dataclass(frozen=True)
+class Point:
+    x: float
+    y: float
+
+
+@dataclass(frozen=True)
+class Size:
+    width: float
+    height: float
+
+
+@dataclass(frozen=True)
+class Rect:
+    left: float
+    right: float
+    top: float
+    bottom: float
+
+
+@dataclass(frozen=True)
+class Layout:
+    order: int
+    location: Point
+    size: Size
+    content_size: Size
+    scrollbar_size: Size
+    border: Rect
+    padding: Rect
+
+    @classmethod
+    def _from_ffi(cls, layout: _ffi.Layout) -> Layout:
+        return cls(
+            order=layout.order,
+            location=Point(layout.location.x, layout.location.y),
+            size=_layout_size(layout.size),
+            content_size=_layout_size(layout.content_size),
+            scrollbar_size=_layout_size(layout.scrollbar_size),
+            border=_layout_rect(layout.border),
+            padding=_layout_rect(layout.padding),
+        )
+
+
+def _layout_size(size: _ffi.SizeFloat) -> Size:
+    return Size(size.width, size.height)
+
+
+def _layout_rect(rect: _ffi.RectFloat) -> Rect:
+    return Rect(rect.left, rect.right, rect.top, rect.bottom)
+
+
+class Style(_ffi.Style):
+    """A style that can be built from keyword arguments.
+
+    Keywords are the setter names without ``set_``. A value is either what the
+    setter takes, a number (pixels for lengths), a tuple (``(width, height)``
+    for sizes and gaps, one to four sides in CSS order for rects) or a string,
+    which is parsed as the CSS value of that property::
+
+        Style(display="flex", size=(100, "50%"), margin=(0, "auto"), flex_grow=1)
+    """
+
+    def __init__(self, **properties: Any) -> None:
+        super().__init__()
+        self.set(**properties)
+
+    def set(self, **properties: Any) -> Style:
+        for name, value in properties.items():
+            try:
+                convert = _PROPERTIES[name]
+            except KeyError:
+                raise TypeError(f"unknown style property {name!r}") from None
+            getattr(self, "set_" + name)(convert(value))
+        return self
+
+
+class TaffyTree(_ffi.TaffyTree):
+    """A tree that takes style keywords when creating nodes and returns layouts
+    as dataclasses. Used as a context manager, it drops all of its nodes when
+    the block exits."""
+
+    def __enter__(self) -> TaffyTree:
+        return self
+
+    def __exit__(self, *exc_info: Any) -> None:
+        self.clear()
+
+    def new_leaf(self, style: Optional[_ffi.Style] = None, **properties: Any) -> int:
+        return super().new_leaf(_style(style, properties))
+
+    def new_with_children(
+        self,
+        style: Optional[_ffi.Style] = None,
+        children: Iterable[int] = (),
+        **properties: Any,
+    ) -> int:
+        return super().new_with_children(_style(style, properties), list(children))
+
+    def compute_layout(self, node: int, available_space: Any = None) -> None:
+        """Lays out the tree under node. The available space is a
+        ``SizeAvailableSpace`` or a ``(width, height)`` tuple of numbers,
+        ``"min-content"`` or ``"max-content"``, and defaults to max-content."""
+        super().compute_layout(node, _available_space(available_space))
+
+    def get_layout(self, node: int) -> Layout:
+        return Layout._from_ffi(super().get_layout(node))
+
+    def get_absolute_layout(self, node: int) -> Layout:
+        return Layout._from_ffi(super().get_absolute_layout(node))
+
+    def get_layouts(self, root: int) -> Dict[int, Layout]:
+        return {entry.node: Layout._from_ffi(entry.layout) for entry in super().get_layouts(root)}
+
+    def get_absolute_layouts(self, root: int) -> Dict[int, Layout]:
+        return {
+            entry.node: Layout._from_ffi(entry.layout)
+            for entry in super().get_absolute_layouts(root)
+        }
+
+
+def _style(style: Optional[_ffi.Style], properties: Dict[str, Any]) -> _ffi.Style:
+    if style is None:
+        return Style(**properties)
+    if properties:
+        raise TypeError("pass either a style or style keywords, not both")
+    return style
+
+
+def _available_space(value: Any) -> SizeAvailableSpace:
+    if value is None:
+        value = ("max-content", "max-content")
+    if isinstance(value, SizeAvailableSpace):
+        return value
+    width, height = value
+    return SizeAvailableSpace(width=_available_axis(width), height=_available_axis(height))
+
+
+def _available_axis(value: Any) -> AvailableSpace:
+    if _is_number(value):
+        return AvailableSpace.DEFINITE(float(value))
+    if value == "min-content":
+        return AvailableSpace.MIN_CONTENT()
+    if value == "max-content":
+        return AvailableSpace.MAX_CONTENT()
+    if isinstance(value, AvailableSpace):
+        return value
+    raise ValueError(f"invalid available space {value!r}")
+
+
+def _is_number(value: Any) -> bool:
+    return isinstance(value, (int, float)) and not isinstance(value, bool)
+
+
+def _property(
+    name: str, convert: Callable[[Any], Any] = lambda value: value, css: Optional[str] = None
+) -> Callable[[Any], Any]:
+    # Strings go through the crate's CSS parser, so they accept exactly what
+    # Style.from_css does for the property.
+    css = css or name.replace("_", "-")
+    read = getattr(_ffi.Style, "get_" + name)
+
+    def apply(value: Any) -> Any:
+        if isinstance(value, str):
+            return read(_ffi.Style.from_css(f"{css}: {value}"))
+        return convert(value)
+
+    return apply
+
+
+def _length(cls: Any, css: str, read: Callable[[_ffi.Style], Any]) -> Callable[[Any], Any]:
+    def convert(value: Any) -> Any:
+        if isinstance(value, str):
+            return read(_ffi.Style.from_css(f"{css}: {value}"))
+        if _is_number(value):
+            return cls.LENGTH(float(value))
+        return value
+
+    return convert
+
+
+_dimension = _length(Dimension, "width", lambda style: style.get_size().width)
+_length_percentage = _length(
+    LengthPercentage, "padding-left", lambda style: style.get_padding().left
+)
+_length_percentage_auto = _length(
+    LengthPercentageAuto, "margin-left", lambda style: style.get_margin().left
+)
+
+
+def _pair(
+    cls: Any, element: Callable[[Any], Any], fields: Tuple[str, str] = ("width", "height")
+) -> Callable[[Any], Any]:
+    def convert(value: Any) -> Any:
+        if isinstance(value, cls):
+            return value
+        if isinstance(value, (tuple, list)):
+            first, second = value
+        else:
+            first = second = value
+        return cls(**{fields[0]: element(first), fields[1]: element(second)})
+
+    return convert
+
+
+# Which of the given values each side takes, in CSS shorthand order.
+_SIDES = {1: (0, 0, 0, 0), 2: (0, 1, 0, 1), 3: (0, 1, 2, 1), 4: (0, 1, 2, 3)}
+
+
+def _rect(cls: Any, element: Callable[[Any], Any]) -> Callable[[Any], Any]:
+    def convert(value: Any) -> Any:
+        if isinstance(value, cls):
+            return value
+        values = tuple(value) if isinstance(value, (tuple, list)) else (value,)
+        if len(values) not in _SIDES:
+            raise ValueError(f"expected one to four sides, got {len(values)}")
+        top, right, bottom, left = (element(values[i]) for i in _SIDES[len(values)])
+        return cls(left=left, right=right, top=top, bottom=bottom)
+
+    return convert
+
+
+def _optional_float(value: Any) -> Optional[float]:
+    return None if value is None else float(value)
+
+
+_PROPERTIES: Dict[str, Callable[[Any], Any]] = {
+    "display": _property("display"),
+    "overflow": _property("overflow", _pair(PointOverflow, lambda value: value, ("x", "y"))),
+    "scrollbar_width": _property("scrollbar_width", float),
+    "position": _property("position"),
+    "inset": _property("inset", _rect(RectLengthPercentageAuto, _length_percentage_auto)),
+    "size": _pair(SizeDimension, _dimension),
+    "min_size": _pair(SizeDimension, _dimension),
+    "max_size": _pair(SizeDimension, _dimension),
+    "aspect_ratio": _property("aspect_ratio", _optional_float),
+    "margin": _property("margin", _rect(RectLengthPercentageAuto, _length_percentage_auto)),
+    "padding": _property("padding", _rect(RectLengthPercentage, _length_percentage)),
+    "border": _property(
+        "border", _rect(RectLengthPercentage, _length_percentage), css="border-width"
+    ),
+    "align_items": _property("align_items"),
+    "align_self": _property("align_self"),
+    "justify_items": _property("justify_items"),
+    "justify_self": _property("justify_self"),
+    "align_content": _property("align_content"),
+    "justify_content": _property("justify_content"),
+    "gap": _property("gap", _pair(SizeLengthPercentage, _length_percentage)),
+    "flex_direction": _property("flex_direction"),
+    "flex_wrap": _property("flex_wrap"),
+    "flex_basis": _property("flex_basis", _dimension),
+    "flex_grow": _property("flex_grow", float),
+    "flex_shrink": _property("flex_shrink", float),
+    "grid_template_rows": _property("grid_template_rows"),
+    "grid_template_columns": _property("grid_template_columns"),
+    "grid_auto_rows": _property("grid_auto_rows"),
+    "grid_auto_columns": _property("grid_auto_columns"),
+    "grid_auto_flow": _property("grid_auto_flow"),
+    "grid_row": _property("grid_row"),
+    "grid_column": _property("grid_column"),
+}
@@ -0,0 +1,165 @@
+import dataclasses
+import math
+
+import pytest
+
+from taffy import (
+    AlignItems,
+    AvailableSpace,
+    Dimension,
+    Display,
+    LengthPercentage,
+    LengthPercentageAuto,
+    Point,
+    RectLengthPercentage,
+    RectLengthPercentageAuto,
+    Size,
+    SizeAvailableSpace,
+    SizeDimension,
+    Style,
+    TaffyError,
+    TaffyTree,
+    error_message,
+)
+
+
+def test_style_from_keywords():
+    style = Style(
+        display=Display.GRID,
+        size=(100, "50%"),
+        margin=(0, "auto"),
+        padding=4,
+        flex_grow=2,
+        align_items=AlignItems.CENTER,
+    )
+
+    assert style.get_display() == Display.GRID
+    assert style.get_size() == SizeDimension(
+        width=Dimension.LENGTH(100), height=Dimension.PERCENT(0.5)
+    )
+    assert style.get_margin() == RectLengthPercentageAuto(
+        left=LengthPercentageAuto.AUTO(),
+        right=LengthPercentageAuto.AUTO(),
+        top=LengthPercentageAuto.LENGTH(0),
+        bottom=LengthPercentageAuto.LENGTH(0),
+    )
+    assert style.get_padding() == RectLengthPercentage(
+        **dict.fromkeys(["left", "right", "top", "bottom"], LengthPercentage.LENGTH(4))
+    )
+    assert style.get_flex_grow() == 2
+    assert style.get_align_items() == AlignItems.CENTER
+
+
+def test_style_strings_are_css():
+    style = Style(
+        display="flex",
+        flex_wrap="wrap-reverse",
+        aspect_ratio="16 / 9",
+        border="1px 2px 3px 4px",
+        grid_template_columns="repeat(2, 1fr) 100px",
+    )
+
+    assert style.to_css() == Style.from_css(
+        "display: flex; flex-wrap: wrap-reverse; aspect-ratio: 16 / 9;"
+        "border-width: 1px 2px 3px 4px; grid-template-columns: repeat(2, 1fr) 100px"
+    ).to_css()
+    assert math.isclose(style.get_aspect_ratio(), 16 / 9, rel_tol=1e-6)
+
+
+def test_style_rejects_bad_values():
+    with pytest.raises(TypeError):
+        Style(colour="red")
+    with pytest.raises(TaffyError.InvalidCss):
+        Style(display="sideways")
+    with pytest.raises(TaffyError.InvalidStyleValue) as info:
+        Style(flex_grow=math.nan)
+    assert info.value.property == "flex-grow"
+    assert math.isnan(info.value.value)
+    with pytest.raises(ValueError):
+        Style(margin=(1, 2, 3, 4, 5))
+
+
+def test_lays_out_a_tree():
+    with TaffyTree() as tree:
+        first = tree.new_leaf(size=(50, 20))
+        second = tree.new_leaf(Style(size=(30, 40)))
+        root = tree.new_with_children(children=[first, second], display="flex", padding=10)
+
+        tree.compute_layout(root)
+
+        assert tree.get_layout(root).size == Size(100, 60)
+        assert tree.get_layout(second).location == Point(60, 10)
+        assert list(tree.get_layouts(root)) == [root, first, second]
+
+
+def test_available_space():
+    with TaffyTree() as tree:
+        child = tree.new_leaf(flex_grow=1)
+        root = tree.new_with_children(children=[child], display="flex", size=("100%", "auto"))
+
+        tree.compute_layout(root, (200, "max-content"))
+        assert tree.get_layout(child).size == Size(200, 0)
+
+        tree.compute_layout(
+            root,
+            SizeAvailableSpace(
+                width=AvailableSpace.DEFINITE(80), height=AvailableSpace.MIN_CONTENT()
+            ),
+        )
+        assert tree.get_layout(child).size == Size(80, 0)
+
+        with pytest.raises(ValueError):
+            tree.compute_layout(root, (200, "fit-content"))
+
+
+def test_absolute_layouts():
+    with TaffyTree() as tree:
+        leaf = tree.new_leaf(size=(10, 10))
+        inner = tree.new_with_children(children=[leaf], padding=5)
+        root = tree.new_with_children(children=[inner], padding=(1, 2))
+
+        tree.compute_layout(root)
+
+        assert tree.get_absolute_layout(leaf).location == Point(7, 6)
+        assert tree.get_absolute_layouts(root)[leaf].location == Point(7, 6)
+
+
+def test_layouts_are_frozen_dataclasses():
+    with TaffyTree() as tree:
+        node = tree.new_leaf(size=(10, 20))
+        tree.compute_layout(node)
+        layout = tree.get_layout(node)
+
+    assert dataclasses.asdict(layout)["size"] == {"width": 10, "height": 20}
+    with pytest.raises(dataclasses.FrozenInstanceError):
+        layout.order = 1
+
+
+def test_context_manager_clears_the_tree():
+    with TaffyTree() as tree:
+        node = tree.new_leaf()
+        assert tree.get_total_node_count() == 1
+
+    assert tree.get_total_node_count() == 0
+    with pytest.raises(TaffyError.InvalidInputNode):
+        tree.get_layout(node)
+
+
+def test_tree_errors():
+    with TaffyTree() as tree:
+        child = tree.new_leaf()
+        parent = tree.new_with_children(children=[child])
+
+        with pytest.raises(TaffyError.CyclicChild):
+            tree.insert_child_at_index(child, 0, parent)
+        with pytest.raises(TypeError):
+            tree.new_leaf(Style(), display="flex")
+
+
+def test_errors_carry_their_fields():
+    with pytest.raises(TaffyError.InvalidCss) as info:
+        Style.from_css("display: flex;\n  width: 10qq")
+
+    assert (info.value.line, info.value.column) == (2, 10)
+    assert info.value.reason == "unsupported unit 'qq'"
+    assert error_message(info.value) == "invalid CSS at 2:10: unsupported unit 'qq'"
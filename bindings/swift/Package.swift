@@ -0,0 +1,15 @@
+// swift-tools-version:5.7
+import PackageDescription
+
+let package = Package(
+    name: "Taffy",
+    products: [
+        .library(name: "Taffy", targets: ["Taffy"]),
+    ],
+    targets: [
+        // The C interface of libtaffy; generate.sh writes its header.
+        .systemLibrary(name: "taffyFFI", path: "Sources/taffyFFI"),
+        .target(name: "Taffy", dependencies: ["taffyFFI"]),
+        .testTarget(name: "TaffyTests", dependencies: ["Taffy"]),
+    ]
+)
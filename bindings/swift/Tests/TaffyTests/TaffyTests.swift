@@ -0,0 +1,65 @@
+import Taffy
+import XCTest
+
+final class TaffyTests: XCTestCase {
+    private let maxContent = SizeAvailableSpace(width: .maxContent, height: .maxContent)
+
+    func testLaysOutAFlexRow() throws {
+        let tree = TaffyTree()
+        let first = try tree.newLeaf(layout: Style.fromCss(css: "width: 50px; height: 20px"))
+        let style = Style()
+        try style.setSize(value: SizeDimension(width: .length(value: 30), height: .length(value: 40)))
+        let second = try tree.newLeaf(layout: style)
+        let root = try tree.newWithChildren(
+            layout: Style.fromCss(css: "display: flex; padding: 10px"),
+            children: [first, second]
+        )
+
+        try tree.computeLayout(node: root, availableSpace: maxContent)
+
+        XCTAssertEqual(try tree.getLayout(node: root).size, SizeFloat(width: 100, height: 60))
+        XCTAssertEqual(try tree.getLayout(node: second).location, PointFloat(x: 60, y: 10))
+        XCTAssertEqual(try tree.getChildren(parent: root), [first, second])
+    }
+
+    func testReadsStylesBack() throws {
+        let style = try Style.fromCss(css: "display: grid; flex-grow: 2")
+
+        XCTAssertEqual(try style.getDisplay(), .grid)
+        XCTAssertEqual(try style.getFlexGrow(), 2)
+        XCTAssertEqual(try style.toCss(), "display: grid;\nflex-grow: 2;")
+    }
+
+    func testThrowsTaffyErrors() throws {
+        XCTAssertThrowsError(try Style().setFlexGrow(value: .nan)) { error in
+            guard case let .InvalidStyleValue(property, value)? = error as? TaffyError else {
+                return XCTFail("unexpected error \(error)")
+            }
+            XCTAssertEqual(property, "flex-grow")
+            XCTAssertTrue(value.isNaN)
+        }
+        XCTAssertThrowsError(try Style.fromCss(css: "display: flex;\n  width: 10qq")) { error in
+            guard let error = error as? TaffyError, case let .InvalidCss(line, column, _) = error else {
+                return XCTFail("unexpected error \(error)")
+            }
+            XCTAssertEqual(line, 2)
+            XCTAssertEqual(column, 10)
+            XCTAssertEqual(errorMessage(error: error), "invalid CSS at 2:10: unsupported unit 'qq'")
+        }
+
+        let tree = TaffyTree()
+        let child = try tree.newLeaf(layout: Style())
+        let parent = try tree.newWithChildren(layout: Style(), children: [child])
+        XCTAssertThrowsError(try tree.insertChildAtIndex(parent: child, childIndex: 0, child: parent)) { error in
+            guard case .CyclicChild? = error as? TaffyError else {
+                return XCTFail("unexpected error \(error)")
+            }
+        }
+        _ = try tree.remove(node: child)
+        XCTAssertThrowsError(try tree.getLayout(node: child)) { error in
+            guard case .InvalidInputNode? = error as? TaffyError else {
+                return XCTFail("unexpected error \(error)")
+            }
+        }
+    }
+}
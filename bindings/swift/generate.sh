@@ -0,0 +1,18 @@
+#!/bin/sh
+# Builds the release library and generates the Swift sources and the C header
+# of the package from src/taffy.udl.
+set -eu
+
+package="$(cd "$(dirname "$0")" && pwd)"
+out="$(mktemp -d)"
+trap 'rm -rf "$out"' EXIT
+
+cd "$package/../.."
+cargo build --release --lib
+cargo run --features bindgen --bin uniffi-bindgen -- generate src/taffy.udl \
+    --language swift --config uniffi.toml --out-dir "$out"
+
+# Sources/Taffy only holds the generated file, so a fresh clone lacks it.
+mkdir -p "$package/Sources/Taffy"
+cp "$out/taffy.swift" "$package/Sources/Taffy/Taffy.swift"
+cp "$out/taffyFFI.h" "$package/Sources/taffyFFI/taffyFFI.h"
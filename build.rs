@@ -1,3 +1,18 @@
+use std::{env, fs, path::PathBuf};
+
 fn main() {
-    uniffi::generate_scaffolding("src/taffy.udl").unwrap();
+    if env::var_os("CARGO_FEATURE_TEXT").is_none() {
+        uniffi::generate_scaffolding("src/taffy.udl").unwrap();
+        return;
+    }
+
+    // UDL has no way to cfg-gate definitions, so the text interfaces live in
+    // their own file which is appended to the main one when the feature is on.
+    println!("cargo:rerun-if-changed=src/taffy.udl");
+    println!("cargo:rerun-if-changed=src/text.udl");
+    let udl =
+        fs::read_to_string("src/taffy.udl").unwrap() + &fs::read_to_string("src/text.udl").unwrap();
+    let udl_file = PathBuf::from(env::var("OUT_DIR").unwrap()).join("taffy.udl");
+    fs::write(&udl_file, udl).unwrap();
+    uniffi::generate_scaffolding_for_crate(udl_file.to_str().unwrap(), "taffy").unwrap();
 }
@@ -0,0 +1,572 @@
+#![no_main]
+
+// Drives a TaffyTree through arbitrary sequences of hierarchy changes, style
+// changes and layouts. None of them may panic, and after every step the tree
+// has to agree with itself about who is whose parent. Panics inside taffy are
+// allowed to surface as internal errors, as long as the tree recovers.
+
+use std::{
+    collections::{HashMap, HashSet},
+    panic::{self, AssertUnwindSafe},
+    process,
+    sync::Arc,
+};
+
+use arbitrary::Arbitrary;
+use libfuzzer_sys::fuzz_target;
+use taffy::{
+    AlignContent, AlignItems, AvailableSpace, Dimension, Display, FlexDirection, FlexWrap,
+    GridAutoFlow, GridPlacement, GridTrackRepetition, LengthPercentage, LengthPercentageAuto,
+    LineGridPlacement, MaxTrackSizingFunction, MinTrackSizingFunction, NodeId,
+    NonRepeatedTrackSizingFunction, Overflow, PointOverflow, Position, RectLengthPercentage,
+    RectLengthPercentageAuto, SizeAvailableSpace, SizeDimension, SizeLengthPercentage, Style,
+    TaffyError, TaffyTree, TrackSizingFunction,
+};
+
+#[derive(Arbitrary, Debug)]
+enum Operation {
+    NewLeaf(Vec<Property>),
+    NewWithChildren(Vec<Property>, Vec<Node>),
+    InsertChildAtIndex {
+        parent: Node,
+        child_index: u8,
+        child: Node,
+    },
+    Remove(Node),
+    RemoveChild {
+        parent: Node,
+        child: Node,
+    },
+    RemoveChildAtIndex {
+        parent: Node,
+        child_index: u8,
+    },
+    ReplaceChildAtIndex {
+        parent: Node,
+        child_index: u8,
+        new_child: Node,
+    },
+    SetChildren {
+        parent: Node,
+        children: Vec<Node>,
+    },
+    SetStyle(Node, Vec<Property>),
+    ComputeLayout(Node, Space, Space),
+    Clear,
+}
+
+// Either one of the ids handed out so far, removed ones included, or an
+// arbitrary id the tree never handed out.
+#[derive(Arbitrary, Debug, Clone, Copy)]
+enum Node {
+    Created(u8),
+    Raw(u64),
+}
+
+#[derive(Arbitrary, Debug)]
+enum Space {
+    Definite(Value),
+    MinContent,
+    MaxContent,
+}
+
+// Lengths are kept finite and small, in quarter pixels.
+#[derive(Arbitrary, Debug, Clone, Copy)]
+struct Value(i16);
+
+#[derive(Arbitrary, Debug, Clone, Copy)]
+enum Length {
+    Fixed(Value),
+    Percent(i8),
+    Auto,
+}
+
+#[derive(Arbitrary, Debug)]
+enum Track {
+    Single(Length, Length, Option<Value>),
+    Repeat(Repetition, Vec<(Length, Length, Option<Value>)>),
+}
+
+#[derive(Arbitrary, Debug)]
+enum Repetition {
+    AutoFill,
+    AutoFit,
+    Count(u8),
+}
+
+#[derive(Arbitrary, Debug)]
+enum Placement {
+    Line(i8),
+    Span(u8),
+    Auto,
+}
+
+#[derive(Arbitrary, Debug)]
+enum Property {
+    Display(u8),
+    Position(u8),
+    Overflow(u8, u8),
+    ScrollbarWidth(Value),
+    Inset([Length; 4]),
+    Size(Length, Length),
+    MinSize(Length, Length),
+    MaxSize(Length, Length),
+    AspectRatio(Option<Value>),
+    Margin([Length; 4]),
+    Padding([Length; 4]),
+    Border([Length; 4]),
+    AlignItems(Option<u8>),
+    AlignSelf(Option<u8>),
+    JustifyItems(Option<u8>),
+    JustifySelf(Option<u8>),
+    AlignContent(Option<u8>),
+    JustifyContent(Option<u8>),
+    Gap(Length, Length),
+    FlexDirection(u8),
+    FlexWrap(u8),
+    FlexBasis(Length),
+    FlexGrow(Value),
+    FlexShrink(Value),
+    GridTemplateRows(Vec<Track>),
+    GridTemplateColumns(Vec<Track>),
+    GridAutoRows(Vec<(Length, Length, Option<Value>)>),
+    GridAutoColumns(Vec<(Length, Length, Option<Value>)>),
+    GridAutoFlow(u8),
+    GridRow(Placement, Placement),
+    GridColumn(Placement, Placement),
+}
+
+impl Value {
+    fn get(self) -> f32 {
+        self.0 as f32 / 4.0
+    }
+}
+
+impl Length {
+    fn dimension(self) -> Dimension {
+        match self {
+            Self::Fixed(value) => Dimension::Length { value: value.get() },
+            Self::Percent(percent) => Dimension::Percent {
+                value: percent as f32 / 100.0,
+            },
+            Self::Auto => Dimension::Auto,
+        }
+    }
+
+    fn length_percentage(self) -> LengthPercentage {
+        match self.dimension() {
+            Dimension::Length { value } => LengthPercentage::Length { value },
+            Dimension::Percent { value } => LengthPercentage::Percent { value },
+            Dimension::Auto => LengthPercentage::Length { value: 0.0 },
+        }
+    }
+
+    fn length_percentage_auto(self) -> LengthPercentageAuto {
+        match self.dimension() {
+            Dimension::Length { value } => LengthPercentageAuto::Length { value },
+            Dimension::Percent { value } => LengthPercentageAuto::Percent { value },
+            Dimension::Auto => LengthPercentageAuto::Auto,
+        }
+    }
+}
+
+fn track_function(
+    &(min, max, fraction): &(Length, Length, Option<Value>),
+) -> NonRepeatedTrackSizingFunction {
+    NonRepeatedTrackSizingFunction {
+        min: match min {
+            Length::Auto => MinTrackSizingFunction::Auto,
+            min => MinTrackSizingFunction::Fixed {
+                value: min.length_percentage(),
+            },
+        },
+        max: match (fraction, max) {
+            (Some(fraction), _) => MaxTrackSizingFunction::Fraction {
+                value: fraction.get(),
+            },
+            (None, Length::Auto) => MaxTrackSizingFunction::MaxContent,
+            (None, max) => MaxTrackSizingFunction::Fixed {
+                value: max.length_percentage(),
+            },
+        },
+    }
+}
+
+fn tracks(tracks: &[Track]) -> Vec<TrackSizingFunction> {
+    tracks
+        .iter()
+        .map(|track| match track {
+            Track::Single(min, max, fraction) => TrackSizingFunction::Single {
+                func: track_function(&(*min, *max, *fraction)),
+            },
+            Track::Repeat(repetition, functions) => TrackSizingFunction::Repeat {
+                rep: match repetition {
+                    Repetition::AutoFill => GridTrackRepetition::AutoFill,
+                    Repetition::AutoFit => GridTrackRepetition::AutoFit,
+                    Repetition::Count(count) => GridTrackRepetition::Count {
+                        value: *count as u16,
+                    },
+                },
+                funcs: functions.iter().map(track_function).collect(),
+            },
+        })
+        .collect()
+}
+
+fn placement(placement: &Placement) -> GridPlacement {
+    match placement {
+        Placement::Line(index) => GridPlacement::Line {
+            index: *index as i16,
+        },
+        Placement::Span(span) => GridPlacement::Span { span: *span as u16 },
+        Placement::Auto => GridPlacement::Auto,
+    }
+}
+
+fn length_percentage_rect(values: &[Length; 4]) -> RectLengthPercentage {
+    RectLengthPercentage {
+        left: values[0].length_percentage(),
+        right: values[1].length_percentage(),
+        top: values[2].length_percentage(),
+        bottom: values[3].length_percentage(),
+    }
+}
+
+fn length_percentage_auto_rect(values: &[Length; 4]) -> RectLengthPercentageAuto {
+    RectLengthPercentageAuto {
+        left: values[0].length_percentage_auto(),
+        right: values[1].length_percentage_auto(),
+        top: values[2].length_percentage_auto(),
+        bottom: values[3].length_percentage_auto(),
+    }
+}
+
+fn pick<T: Copy>(options: &[T], index: u8) -> T {
+    options[index as usize % options.len()]
+}
+
+const ALIGN_ITEMS: &[AlignItems] = &[
+    AlignItems::Start,
+    AlignItems::End,
+    AlignItems::FlexStart,
+    AlignItems::FlexEnd,
+    AlignItems::Center,
+    AlignItems::Baseline,
+    AlignItems::Stretch,
+];
+const ALIGN_CONTENT: &[AlignContent] = &[
+    AlignContent::Start,
+    AlignContent::End,
+    AlignContent::FlexStart,
+    AlignContent::FlexEnd,
+    AlignContent::Center,
+    AlignContent::Stretch,
+    AlignContent::SpaceBetween,
+    AlignContent::SpaceEvenly,
+    AlignContent::SpaceAround,
+];
+const OVERFLOW: &[Overflow] = &[
+    Overflow::Visible,
+    Overflow::Clip,
+    Overflow::Hidden,
+    Overflow::Scroll,
+];
+
+fn style(properties: &[Property]) -> Arc<Style> {
+    let style = Style::new();
+    for property in properties {
+        let result = match property {
+            Property::Display(value) => style.set_display(pick(
+                &[Display::Block, Display::Flex, Display::Grid, Display::None],
+                *value,
+            )),
+            Property::Position(value) => {
+                style.set_position(pick(&[Position::Relative, Position::Absolute], *value))
+            }
+            Property::Overflow(x, y) => style.set_overflow(PointOverflow {
+                x: pick(OVERFLOW, *x),
+                y: pick(OVERFLOW, *y),
+            }),
+            Property::ScrollbarWidth(value) => style.set_scrollbar_width(value.get()),
+            Property::Inset(values) => style.set_inset(length_percentage_auto_rect(values)),
+            Property::Size(width, height) => style.set_size(SizeDimension {
+                width: width.dimension(),
+                height: height.dimension(),
+            }),
+            Property::MinSize(width, height) => style.set_min_size(SizeDimension {
+                width: width.dimension(),
+                height: height.dimension(),
+            }),
+            Property::MaxSize(width, height) => style.set_max_size(SizeDimension {
+                width: width.dimension(),
+                height: height.dimension(),
+            }),
+            Property::AspectRatio(value) => style.set_aspect_ratio(value.map(Value::get)),
+            Property::Margin(values) => style.set_margin(length_percentage_auto_rect(values)),
+            Property::Padding(values) => style.set_padding(length_percentage_rect(values)),
+            Property::Border(values) => style.set_border(length_percentage_rect(values)),
+            Property::AlignItems(value) => {
+                style.set_align_items(value.map(|value| pick(ALIGN_ITEMS, value)))
+            }
+            Property::AlignSelf(value) => {
+                style.set_align_self(value.map(|value| pick(ALIGN_ITEMS, value)))
+            }
+            Property::JustifyItems(value) => {
+                style.set_justify_items(value.map(|value| pick(ALIGN_ITEMS, value)))
+            }
+            Property::JustifySelf(value) => {
+                style.set_justify_self(value.map(|value| pick(ALIGN_ITEMS, value)))
+            }
+            Property::AlignContent(value) => {
+                style.set_align_content(value.map(|value| pick(ALIGN_CONTENT, value)))
+            }
+            Property::JustifyContent(value) => {
+                style.set_justify_content(value.map(|value| pick(ALIGN_CONTENT, value)))
+            }
+            Property::Gap(width, height) => style.set_gap(SizeLengthPercentage {
+                width: width.length_percentage(),
+                height: height.length_percentage(),
+            }),
+            Property::FlexDirection(value) => style.set_flex_direction(pick(
+                &[
+                    FlexDirection::Row,
+                    FlexDirection::Column,
+                    FlexDirection::RowReverse,
+                    FlexDirection::ColumnReverse,
+                ],
+                *value,
+            )),
+            Property::FlexWrap(value) => style.set_flex_wrap(pick(
+                &[FlexWrap::NoWrap, FlexWrap::Wrap, FlexWrap::WrapReverse],
+                *value,
+            )),
+            Property::FlexBasis(value) => style.set_flex_basis(value.dimension()),
+            Property::FlexGrow(value) => style.set_flex_grow(value.get()),
+            Property::FlexShrink(value) => style.set_flex_shrink(value.get()),
+            Property::GridTemplateRows(value) => style.set_grid_template_rows(tracks(value)),
+            Property::GridTemplateColumns(value) => style.set_grid_template_columns(tracks(value)),
+            Property::GridAutoRows(value) => {
+                style.set_grid_auto_rows(value.iter().map(track_function).collect())
+            }
+            Property::GridAutoColumns(value) => {
+                style.set_grid_auto_columns(value.iter().map(track_function).collect())
+            }
+            Property::GridAutoFlow(value) => style.set_grid_auto_flow(pick(
+                &[
+                    GridAutoFlow::Row,
+                    GridAutoFlow::Column,
+                    GridAutoFlow::RowDense,
+                    GridAutoFlow::ColumnDense,
+                ],
+                *value,
+            )),
+            Property::GridRow(start, end) => style.set_grid_row(LineGridPlacement {
+                start: placement(start),
+                end: placement(end),
+            }),
+            Property::GridColumn(start, end) => style.set_grid_column(LineGridPlacement {
+                start: placement(start),
+                end: placement(end),
+            }),
+        };
+        // Values the setters reject, like negative sizes, are just skipped.
+        if let Err(error) = result {
+            assert!(
+                matches!(error, TaffyError::InvalidStyleValue { .. }),
+                "{error}"
+            );
+        }
+    }
+    Arc::new(style)
+}
+
+fn available_space(space: &Space) -> AvailableSpace {
+    match space {
+        Space::Definite(value) => AvailableSpace::Definite { value: value.get() },
+        Space::MinContent => AvailableSpace::MinContent,
+        Space::MaxContent => AvailableSpace::MaxContent,
+    }
+}
+
+struct Harness {
+    tree: TaffyTree,
+    created: Vec<NodeId>,
+    live: HashSet<NodeId>,
+}
+
+impl Harness {
+    fn node(&self, node: Node) -> NodeId {
+        match node {
+            Node::Created(index) if !self.created.is_empty() => {
+                self.created[index as usize % self.created.len()]
+            }
+            Node::Created(index) => NodeId::from(index as u64),
+            Node::Raw(id) => NodeId::from(id),
+        }
+    }
+
+    fn created(&mut self, node: NodeId) {
+        self.created.push(node);
+        self.live.insert(node);
+    }
+
+    fn apply(&mut self, operation: &Operation) {
+        let result = match operation {
+            Operation::NewLeaf(properties) => self
+                .tree
+                .new_leaf(style(properties))
+                .map(|node| self.created(node)),
+            Operation::NewWithChildren(properties, children) => {
+                let children = children.iter().map(|&child| self.node(child)).collect();
+                self.tree
+                    .new_with_children(style(properties), children)
+                    .map(|node| self.created(node))
+            }
+            Operation::InsertChildAtIndex {
+                parent,
+                child_index,
+                child,
+            } => self.tree.insert_child_at_index(
+                self.node(*parent),
+                *child_index as u64,
+                self.node(*child),
+            ),
+            Operation::Remove(node) => {
+                let node = self.node(*node);
+                self.tree.remove(node).map(|_| {
+                    self.live.remove(&node);
+                })
+            }
+            Operation::RemoveChild { parent, child } => self
+                .tree
+                .remove_child(self.node(*parent), self.node(*child))
+                .map(|_| ()),
+            Operation::RemoveChildAtIndex {
+                parent,
+                child_index,
+            } => self
+                .tree
+                .remove_child_at_index(self.node(*parent), *child_index as u64)
+                .map(|_| ()),
+            Operation::ReplaceChildAtIndex {
+                parent,
+                child_index,
+                new_child,
+            } => self
+                .tree
+                .replace_child_at_index(
+                    self.node(*parent),
+                    *child_index as u64,
+                    self.node(*new_child),
+                )
+                .map(|_| ()),
+            Operation::SetChildren { parent, children } => self.tree.set_children(
+                self.node(*parent),
+                children.iter().map(|&child| self.node(child)).collect(),
+            ),
+            Operation::SetStyle(node, properties) => {
+                self.tree.set_style(self.node(*node), style(properties))
+            }
+            Operation::ComputeLayout(node, width, height) => self.tree.compute_layout(
+                self.node(*node),
+                SizeAvailableSpace {
+                    width: available_space(width),
+                    height: available_space(height),
+                },
+            ),
+            Operation::Clear => self.tree.clear().map(|_| self.live.clear()),
+        };
+        // A panic inside taffy comes back as an internal error, after which
+        // the tree has to be as consistent as before. Anything else means the
+        // call failed somewhere it shouldn't have.
+        if let Err(TaffyError::Internal { .. }) = result {
+            self.tree.recover().unwrap();
+        } else if let Err(error) = result {
+            assert!(
+                matches!(
+                    error,
+                    TaffyError::ChildIndexOutOfBounds { .. }
+                        | TaffyError::InvalidParentNode { .. }
+                        | TaffyError::InvalidChildNode { .. }
+                        | TaffyError::InvalidInputNode { .. }
+                        | TaffyError::NotAChild { .. }
+                        | TaffyError::DuplicateChild { .. }
+                        | TaffyError::CyclicChild { .. }
+                ),
+                "{operation:?} failed with {error}"
+            );
+        }
+    }
+
+    fn check_invariants(&self) {
+        assert_eq!(
+            self.tree.get_total_node_count().unwrap(),
+            self.live.len() as u64
+        );
+        let mut parents = HashMap::new();
+        for &node in &self.live {
+            for child in self.tree.get_children(node).unwrap() {
+                assert!(
+                    self.live.contains(&child),
+                    "{node:?} has removed child {child:?}"
+                );
+                assert_eq!(self.tree.get_parent(child).unwrap(), Some(node));
+                if let Some(other) = parents.insert(child, node) {
+                    panic!("{child:?} is a child of both {other:?} and {node:?}");
+                }
+            }
+            self.tree.get_layout(node).unwrap();
+        }
+        for &node in &self.live {
+            assert_eq!(
+                self.tree.get_parent(node).unwrap(),
+                parents.get(&node).copied()
+            );
+            // Walking up from any node has to reach a root.
+            let mut current = node;
+            for _ in 0..=self.live.len() {
+                match parents.get(&current) {
+                    Some(&parent) => current = parent,
+                    None => break,
+                }
+            }
+            assert!(!parents.contains_key(&current), "{node:?} is in a cycle");
+        }
+        for &node in &self.created {
+            if !self.live.contains(&node) {
+                assert!(self.tree.get_parent(node).is_err());
+            }
+        }
+    }
+}
+
+fuzz_target!(
+    init: {
+        // The wrapper turns taffy's panics into errors, so the hook
+        // libfuzzer-sys installs, which aborts on every panic, is replaced.
+        // Panics that do get out are reported below instead.
+        panic::set_hook(Box::new(|_| {}));
+    },
+    |operations: Vec<Operation>| {
+        let mut harness = Harness {
+            tree: TaffyTree::new(),
+            created: Vec::new(),
+            live: HashSet::new(),
+        };
+        for operation in &operations {
+            let step = panic::catch_unwind(AssertUnwindSafe(|| {
+                harness.apply(operation);
+                harness.check_invariants();
+            }));
+            if let Err(payload) = step {
+                let message = payload
+                    .downcast_ref::<&str>()
+                    .map(|message| message.to_string())
+                    .or_else(|| payload.downcast_ref::<String>().cloned())
+                    .unwrap_or_default();
+                eprintln!("{operation:?} panicked: {message}");
+                process::abort();
+            }
+        }
+    }
+);
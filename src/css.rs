@@ -0,0 +1,1177 @@
+use taffy::{
+    style_helpers::{TaffyGridLine, TaffyGridSpan},
+    AlignContent, AlignItems, Dimension, Display, FlexDirection, FlexWrap, GridAutoFlow,
+    GridPlacement, GridTrackRepetition, LengthPercentage, LengthPercentageAuto, Line,
+    MaxTrackSizingFunction, MinTrackSizingFunction, NonRepeatedTrackSizingFunction, Overflow,
+    Position, Rect, Size, TrackSizingFunction,
+};
+
+use crate::{TaffyError, TaffyResult};
+
+// Parses the declarations in `css` into a copy of `style`, so nothing is
+// applied unless every declaration is valid.
+pub(crate) fn apply_css(style: &taffy::Style, css: &str) -> TaffyResult<taffy::Style> {
+    let mut result = style.clone();
+    parse_declarations(&mut result, css).map_err(|error| error.locate(css))?;
+    Ok(result)
+}
+
+// Like apply_css, for declarations embedded in a larger document such as a
+// style attribute. Errors are located within the whole of `source`.
+pub(crate) fn apply_embedded_css(
+    style: &taffy::Style,
+    source: &str,
+    start: usize,
+    end: usize,
+) -> TaffyResult<taffy::Style> {
+    let mut result = style.clone();
+    parse_declarations(&mut result, &source[start..end]).map_err(|mut error| {
+        error.position += start;
+        error.locate(source)
+    })?;
+    Ok(result)
+}
+
+// Writes the declarations that differ from the default style, one per line.
+// NaN and infinities have no CSS form, so a style holding one is an error.
+pub(crate) fn to_css(style: &taffy::Style) -> TaffyResult<String> {
+    let default = taffy::Style::DEFAULT;
+    let mut declarations = Vec::new();
+    let mut declare = |property: &str, value: CssResult| {
+        let value = value.map_err(|value| TaffyError::InvalidStyleValue {
+            property: property.to_owned(),
+            value,
+        })?;
+        declarations.push(format!("{property}: {value};"));
+        TaffyResult::Ok(())
+    };
+
+    if style.display != default.display {
+        declare(
+            "display",
+            Ok(keyword_name(DISPLAY, style.display).to_owned()),
+        )?;
+    }
+    if style.overflow != default.overflow {
+        let x = keyword_name(OVERFLOW, style.overflow.x);
+        let y = keyword_name(OVERFLOW, style.overflow.y);
+        declare(
+            "overflow",
+            Ok(if x == y {
+                x.to_owned()
+            } else {
+                format!("{x} {y}")
+            }),
+        )?;
+    }
+    if style.scrollbar_width != default.scrollbar_width {
+        declare("scrollbar-width", length_css(style.scrollbar_width))?;
+    }
+    if style.position != default.position {
+        declare(
+            "position",
+            Ok(keyword_name(POSITION, style.position).to_owned()),
+        )?;
+    }
+    if style.inset != default.inset {
+        declare("inset", rect_css(style.inset, length_percentage_auto_css))?;
+    }
+    let sizes = [
+        ("width", style.size.width, default.size.width),
+        ("height", style.size.height, default.size.height),
+        ("min-width", style.min_size.width, default.min_size.width),
+        ("min-height", style.min_size.height, default.min_size.height),
+        ("max-width", style.max_size.width, default.max_size.width),
+        ("max-height", style.max_size.height, default.max_size.height),
+    ];
+    for (property, value, default) in sizes {
+        if value != default {
+            declare(property, dimension_css(value))?;
+        }
+    }
+    if let Some(aspect_ratio) = style.aspect_ratio {
+        declare("aspect-ratio", number_css(aspect_ratio))?;
+    }
+    if style.margin != default.margin {
+        declare("margin", rect_css(style.margin, length_percentage_auto_css))?;
+    }
+    if style.padding != default.padding {
+        declare("padding", rect_css(style.padding, length_percentage_css))?;
+    }
+    if style.border != default.border {
+        declare(
+            "border-width",
+            rect_css(style.border, length_percentage_css),
+        )?;
+    }
+    let alignments = [
+        ("align-items", style.align_items),
+        ("align-self", style.align_self),
+        ("justify-items", style.justify_items),
+        ("justify-self", style.justify_self),
+    ];
+    for (property, value) in alignments {
+        if let Some(value) = value {
+            declare(property, Ok(keyword_name(ALIGN_ITEMS, value).to_owned()))?;
+        }
+    }
+    let content_alignments = [
+        ("align-content", style.align_content),
+        ("justify-content", style.justify_content),
+    ];
+    for (property, value) in content_alignments {
+        if let Some(value) = value {
+            declare(property, Ok(keyword_name(ALIGN_CONTENT, value).to_owned()))?;
+        }
+    }
+    if style.gap != default.gap {
+        let gap = length_percentage_css(style.gap.height).and_then(|row| {
+            let column = length_percentage_css(style.gap.width)?;
+            Ok(if row == column {
+                row
+            } else {
+                format!("{row} {column}")
+            })
+        });
+        declare("gap", gap)?;
+    }
+    if style.flex_direction != default.flex_direction {
+        declare(
+            "flex-direction",
+            Ok(keyword_name(FLEX_DIRECTION, style.flex_direction).to_owned()),
+        )?;
+    }
+    if style.flex_wrap != default.flex_wrap {
+        declare(
+            "flex-wrap",
+            Ok(keyword_name(FLEX_WRAP, style.flex_wrap).to_owned()),
+        )?;
+    }
+    if style.flex_basis != default.flex_basis {
+        declare("flex-basis", dimension_css(style.flex_basis))?;
+    }
+    if style.flex_grow != default.flex_grow {
+        declare("flex-grow", number_css(style.flex_grow))?;
+    }
+    if style.flex_shrink != default.flex_shrink {
+        declare("flex-shrink", number_css(style.flex_shrink))?;
+    }
+    let track_lists = [
+        ("grid-template-rows", &style.grid_template_rows),
+        ("grid-template-columns", &style.grid_template_columns),
+    ];
+    for (property, tracks) in track_lists {
+        if !tracks.is_empty() {
+            declare(property, join_css(tracks, track_sizing_function_css))?;
+        }
+    }
+    let auto_tracks = [
+        ("grid-auto-rows", &style.grid_auto_rows),
+        ("grid-auto-columns", &style.grid_auto_columns),
+    ];
+    for (property, tracks) in auto_tracks {
+        if !tracks.is_empty() {
+            declare(property, join_css(tracks, track_size_css))?;
+        }
+    }
+    if style.grid_auto_flow != default.grid_auto_flow {
+        let flow = match style.grid_auto_flow {
+            GridAutoFlow::Row => "row",
+            GridAutoFlow::Column => "column",
+            GridAutoFlow::RowDense => "row dense",
+            GridAutoFlow::ColumnDense => "column dense",
+        };
+        declare("grid-auto-flow", Ok(flow.to_owned()))?;
+    }
+    if style.grid_row != default.grid_row {
+        declare("grid-row", Ok(grid_line_css(style.grid_row)))?;
+    }
+    if style.grid_column != default.grid_column {
+        declare("grid-column", Ok(grid_line_css(style.grid_column)))?;
+    }
+
+    Ok(declarations.join("\n"))
+}
+
+struct ParseError {
+    position: usize,
+    message: String,
+}
+
+impl ParseError {
+    fn new(position: usize, message: impl Into<String>) -> Self {
+        Self {
+            position,
+            message: message.into(),
+        }
+    }
+
+    fn locate(self, css: &str) -> TaffyError {
+        let before = &css[..self.position.min(css.len())];
+        let line_start = before.rfind('\n').map_or(0, |index| index + 1);
+        TaffyError::InvalidCss {
+            line: before.matches('\n').count() as u32 + 1,
+            column: before[line_start..].chars().count() as u32 + 1,
+            reason: self.message,
+        }
+    }
+}
+
+type ParseResult<T> = Result<T, ParseError>;
+
+#[derive(Debug, PartialEq)]
+enum TokenKind {
+    Ident(String),
+    Function(String),
+    Number { value: f32, unit: String },
+    Colon,
+    Semicolon,
+    Comma,
+    Slash,
+    CloseParen,
+    Bang,
+}
+
+struct Token {
+    kind: TokenKind,
+    position: usize,
+}
+
+fn tokenize(css: &str) -> ParseResult<Vec<Token>> {
+    let bytes = css.as_bytes();
+    let mut tokens = Vec::new();
+    let mut position = 0;
+
+    while position < bytes.len() {
+        let start = position;
+        let byte = bytes[position];
+        let kind = match byte {
+            b if b.is_ascii_whitespace() => {
+                position += 1;
+                continue;
+            }
+            b'/' if bytes.get(position + 1) == Some(&b'*') => {
+                position = css[position + 2..]
+                    .find("*/")
+                    .map(|end| position + 2 + end + 2)
+                    .ok_or_else(|| ParseError::new(start, "unterminated comment"))?;
+                continue;
+            }
+            b':' => TokenKind::Colon,
+            b';' => TokenKind::Semicolon,
+            b',' => TokenKind::Comma,
+            b'/' => TokenKind::Slash,
+            b')' => TokenKind::CloseParen,
+            b'!' => TokenKind::Bang,
+            b'0'..=b'9' | b'.' | b'+' => {
+                position = number_end(bytes, position);
+                number_token(css, start, &mut position)?
+            }
+            b'-' if bytes
+                .get(position + 1)
+                .is_some_and(|next| next.is_ascii_digit() || *next == b'.') =>
+            {
+                position = number_end(bytes, position);
+                number_token(css, start, &mut position)?
+            }
+            b if is_ident_byte(b) => {
+                while position < bytes.len() && is_ident_byte(bytes[position]) {
+                    position += 1;
+                }
+                let name = css[start..position].to_ascii_lowercase();
+                if bytes.get(position) == Some(&b'(') {
+                    position += 1;
+                    tokens.push(Token {
+                        kind: TokenKind::Function(name),
+                        position: start,
+                    });
+                } else {
+                    tokens.push(Token {
+                        kind: TokenKind::Ident(name),
+                        position: start,
+                    });
+                }
+                continue;
+            }
+            _ => {
+                let character = css[position..].chars().next().unwrap_or_default();
+                return Err(ParseError::new(
+                    start,
+                    format!("unexpected character '{character}'"),
+                ));
+            }
+        };
+        if position == start {
+            position += 1;
+        }
+        tokens.push(Token {
+            kind,
+            position: start,
+        });
+    }
+
+    Ok(tokens)
+}
+
+fn is_ident_byte(byte: u8) -> bool {
+    byte.is_ascii_alphanumeric() || byte == b'-' || byte == b'_'
+}
+
+fn number_end(bytes: &[u8], mut position: usize) -> usize {
+    if matches!(bytes[position], b'+' | b'-') {
+        position += 1;
+    }
+    while position < bytes.len() && (bytes[position].is_ascii_digit() || bytes[position] == b'.') {
+        position += 1;
+    }
+    position
+}
+
+// Parses the number ending at `position` together with the unit following it.
+// Percentages become fractions.
+fn number_token(css: &str, start: usize, position: &mut usize) -> ParseResult<TokenKind> {
+    let value = css[start..*position].parse::<f32>().map_err(|_| {
+        ParseError::new(
+            start,
+            format!("invalid number '{}'", &css[start..*position]),
+        )
+    })?;
+    let bytes = css.as_bytes();
+    let unit_start = *position;
+    if bytes.get(*position) == Some(&b'%') {
+        *position += 1;
+        return Ok(TokenKind::Number {
+            value: percent_fraction(&css[start..unit_start]),
+            unit: "%".to_owned(),
+        });
+    }
+    while *position < bytes.len() && bytes[*position].is_ascii_alphabetic() {
+        *position += 1;
+    }
+    Ok(TokenKind::Number {
+        value,
+        unit: css[unit_start..*position].to_ascii_lowercase(),
+    })
+}
+
+// Divides in f64, so that a percentage is read as the f32 nearest to its
+// fraction instead of being rounded twice. `number` has already parsed as f32.
+fn percent_fraction(number: &str) -> f32 {
+    (number.parse::<f64>().unwrap_or_default() / 100.0) as f32
+}
+
+fn parse_declarations(style: &mut taffy::Style, css: &str) -> ParseResult<()> {
+    let tokens = tokenize(css)?;
+    let mut index = 0;
+
+    while index < tokens.len() {
+        if tokens[index].kind == TokenKind::Semicolon {
+            index += 1;
+            continue;
+        }
+
+        let TokenKind::Ident(property) = &tokens[index].kind else {
+            return Err(ParseError::new(
+                tokens[index].position,
+                "expected a property name",
+            ));
+        };
+        let property_position = tokens[index].position;
+        index += 1;
+
+        match tokens.get(index) {
+            Some(Token {
+                kind: TokenKind::Colon,
+                ..
+            }) => index += 1,
+            Some(token) => return Err(ParseError::new(token.position, "expected ':'")),
+            None => return Err(ParseError::new(css.len(), "expected ':'")),
+        }
+
+        let value_start = index;
+        while index < tokens.len() && tokens[index].kind != TokenKind::Semicolon {
+            index += 1;
+        }
+        let end = tokens.get(index).map_or(css.len(), |token| token.position);
+        let mut value = Value::new(&tokens[value_start..index], end);
+        value.strip_important();
+        if value.is_empty() {
+            return Err(ParseError::new(
+                end,
+                format!("missing value for '{property}'"),
+            ));
+        }
+
+        apply_declaration(style, property, property_position, &mut value)?;
+        value.expect_end()?;
+    }
+
+    Ok(())
+}
+
+fn apply_declaration(
+    style: &mut taffy::Style,
+    property: &str,
+    position: usize,
+    value: &mut Value,
+) -> ParseResult<()> {
+    match property {
+        "display" => style.display = value.keyword(DISPLAY)?,
+        "overflow" => {
+            let x = value.keyword(OVERFLOW)?;
+            let y = if value.is_empty() {
+                x
+            } else {
+                value.keyword(OVERFLOW)?
+            };
+            style.overflow = taffy::Point { x, y };
+        }
+        "overflow-x" => style.overflow.x = value.keyword(OVERFLOW)?,
+        "overflow-y" => style.overflow.y = value.keyword(OVERFLOW)?,
+        "scrollbar-width" => style.scrollbar_width = value.length()?,
+        "position" => style.position = value.keyword(POSITION)?,
+        "inset" => style.inset = value.rect(Value::length_percentage_auto)?,
+        "top" => style.inset.top = value.length_percentage_auto()?,
+        "right" => style.inset.right = value.length_percentage_auto()?,
+        "bottom" => style.inset.bottom = value.length_percentage_auto()?,
+        "left" => style.inset.left = value.length_percentage_auto()?,
+        "width" => style.size.width = value.dimension()?,
+        "height" => style.size.height = value.dimension()?,
+        "min-width" => style.min_size.width = value.dimension()?,
+        "min-height" => style.min_size.height = value.dimension()?,
+        "max-width" => style.max_size.width = value.dimension()?,
+        "max-height" => style.max_size.height = value.dimension()?,
+        "aspect-ratio" => style.aspect_ratio = value.aspect_ratio()?,
+        "margin" => style.margin = value.rect(Value::length_percentage_auto)?,
+        "margin-top" => style.margin.top = value.length_percentage_auto()?,
+        "margin-right" => style.margin.right = value.length_percentage_auto()?,
+        "margin-bottom" => style.margin.bottom = value.length_percentage_auto()?,
+        "margin-left" => style.margin.left = value.length_percentage_auto()?,
+        "padding" => style.padding = value.rect(Value::length_percentage)?,
+        "padding-top" => style.padding.top = value.length_percentage()?,
+        "padding-right" => style.padding.right = value.length_percentage()?,
+        "padding-bottom" => style.padding.bottom = value.length_percentage()?,
+        "padding-left" => style.padding.left = value.length_percentage()?,
+        "border-width" => style.border = value.rect(Value::length_percentage)?,
+        "border-top-width" => style.border.top = value.length_percentage()?,
+        "border-right-width" => style.border.right = value.length_percentage()?,
+        "border-bottom-width" => style.border.bottom = value.length_percentage()?,
+        "border-left-width" => style.border.left = value.length_percentage()?,
+        "align-items" => style.align_items = value.optional_keyword(ALIGN_ITEMS)?,
+        "align-self" => style.align_self = value.optional_keyword(ALIGN_ITEMS)?,
+        "justify-items" => style.justify_items = value.optional_keyword(ALIGN_ITEMS)?,
+        "justify-self" => style.justify_self = value.optional_keyword(ALIGN_ITEMS)?,
+        "align-content" => style.align_content = value.optional_keyword(ALIGN_CONTENT)?,
+        "justify-content" => style.justify_content = value.optional_keyword(ALIGN_CONTENT)?,
+        "gap" => {
+            let row = value.length_percentage()?;
+            let column = if value.is_empty() {
+                row
+            } else {
+                value.length_percentage()?
+            };
+            style.gap = Size {
+                width: column,
+                height: row,
+            };
+        }
+        "row-gap" => style.gap.height = value.length_percentage()?,
+        "column-gap" => style.gap.width = value.length_percentage()?,
+        "flex-direction" => style.flex_direction = value.keyword(FLEX_DIRECTION)?,
+        "flex-wrap" => style.flex_wrap = value.keyword(FLEX_WRAP)?,
+        "flex-flow" => {
+            while !value.is_empty() {
+                if let Some(direction) = value.try_keyword(FLEX_DIRECTION) {
+                    style.flex_direction = direction;
+                } else {
+                    style.flex_wrap = value.keyword(FLEX_WRAP)?;
+                }
+            }
+        }
+        "flex-basis" => style.flex_basis = value.dimension()?,
+        "flex-grow" => style.flex_grow = value.non_negative_number()?,
+        "flex-shrink" => style.flex_shrink = value.non_negative_number()?,
+        "flex" => {
+            let (grow, shrink, basis) = value.flex()?;
+            style.flex_grow = grow;
+            style.flex_shrink = shrink;
+            style.flex_basis = basis;
+        }
+        "grid-template-rows" => style.grid_template_rows = value.track_list()?,
+        "grid-template-columns" => style.grid_template_columns = value.track_list()?,
+        "grid-auto-rows" => style.grid_auto_rows = value.track_sizes()?,
+        "grid-auto-columns" => style.grid_auto_columns = value.track_sizes()?,
+        "grid-auto-flow" => style.grid_auto_flow = value.grid_auto_flow()?,
+        "grid-row" => style.grid_row = value.grid_line()?,
+        "grid-column" => style.grid_column = value.grid_line()?,
+        "grid-row-start" => style.grid_row.start = value.grid_placement()?,
+        "grid-row-end" => style.grid_row.end = value.grid_placement()?,
+        "grid-column-start" => style.grid_column.start = value.grid_placement()?,
+        "grid-column-end" => style.grid_column.end = value.grid_placement()?,
+        _ => {
+            return Err(ParseError::new(
+                position,
+                format!("unsupported property '{property}'"),
+            ))
+        }
+    }
+    Ok(())
+}
+
+const DISPLAY: &[(&str, Display)] = &[
+    ("block", Display::Block),
+    ("flex", Display::Flex),
+    ("grid", Display::Grid),
+    ("none", Display::None),
+];
+
+const OVERFLOW: &[(&str, Overflow)] = &[
+    ("visible", Overflow::Visible),
+    ("clip", Overflow::Clip),
+    ("hidden", Overflow::Hidden),
+    ("scroll", Overflow::Scroll),
+];
+
+const POSITION: &[(&str, Position)] = &[
+    ("relative", Position::Relative),
+    ("absolute", Position::Absolute),
+];
+
+const ALIGN_ITEMS: &[(&str, AlignItems)] = &[
+    ("start", AlignItems::Start),
+    ("end", AlignItems::End),
+    ("flex-start", AlignItems::FlexStart),
+    ("flex-end", AlignItems::FlexEnd),
+    ("center", AlignItems::Center),
+    ("baseline", AlignItems::Baseline),
+    ("stretch", AlignItems::Stretch),
+];
+
+const ALIGN_CONTENT: &[(&str, AlignContent)] = &[
+    ("start", AlignContent::Start),
+    ("end", AlignContent::End),
+    ("flex-start", AlignContent::FlexStart),
+    ("flex-end", AlignContent::FlexEnd),
+    ("center", AlignContent::Center),
+    ("stretch", AlignContent::Stretch),
+    ("space-between", AlignContent::SpaceBetween),
+    ("space-evenly", AlignContent::SpaceEvenly),
+    ("space-around", AlignContent::SpaceAround),
+];
+
+const FLEX_DIRECTION: &[(&str, FlexDirection)] = &[
+    ("row", FlexDirection::Row),
+    ("column", FlexDirection::Column),
+    ("row-reverse", FlexDirection::RowReverse),
+    ("column-reverse", FlexDirection::ColumnReverse),
+];
+
+const FLEX_WRAP: &[(&str, FlexWrap)] = &[
+    ("nowrap", FlexWrap::NoWrap),
+    ("wrap", FlexWrap::Wrap),
+    ("wrap-reverse", FlexWrap::WrapReverse),
+];
+
+// The tokens of a single declaration value.
+struct Value<'t> {
+    tokens: &'t [Token],
+    index: usize,
+    end: usize,
+}
+
+impl<'t> Value<'t> {
+    fn new(tokens: &'t [Token], end: usize) -> Self {
+        Self {
+            tokens,
+            index: 0,
+            end,
+        }
+    }
+
+    fn strip_important(&mut self) {
+        if let [rest @ .., bang, important] = self.tokens {
+            if bang.kind == TokenKind::Bang
+                && important.kind == TokenKind::Ident("important".to_owned())
+            {
+                self.end = bang.position;
+                self.tokens = rest;
+            }
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.index >= self.tokens.len()
+    }
+
+    fn position(&self) -> usize {
+        self.tokens
+            .get(self.index)
+            .map_or(self.end, |token| token.position)
+    }
+
+    fn error<T>(&self, message: impl Into<String>) -> ParseResult<T> {
+        Err(ParseError::new(self.position(), message))
+    }
+
+    fn peek(&self) -> Option<&'t TokenKind> {
+        self.tokens.get(self.index).map(|token| &token.kind)
+    }
+
+    fn expect_end(&self) -> ParseResult<()> {
+        if self.is_empty() {
+            Ok(())
+        } else {
+            self.error("unexpected trailing value")
+        }
+    }
+
+    fn expect(&mut self, expected: TokenKind, description: &str) -> ParseResult<()> {
+        if self.peek() == Some(&expected) {
+            self.index += 1;
+            Ok(())
+        } else {
+            self.error(format!("expected {description}"))
+        }
+    }
+
+    fn eat(&mut self, expected: TokenKind) -> bool {
+        let found = self.peek() == Some(&expected);
+        if found {
+            self.index += 1;
+        }
+        found
+    }
+
+    fn eat_ident(&mut self, ident: &str) -> bool {
+        let found = matches!(self.peek(), Some(TokenKind::Ident(name)) if name == ident);
+        if found {
+            self.index += 1;
+        }
+        found
+    }
+
+    fn try_keyword<T: Copy>(&mut self, keywords: &[(&str, T)]) -> Option<T> {
+        let Some(TokenKind::Ident(name)) = self.peek() else {
+            return None;
+        };
+        let value = keywords
+            .iter()
+            .find(|(keyword, _)| keyword == name)
+            .map(|(_, value)| *value)?;
+        self.index += 1;
+        Some(value)
+    }
+
+    fn keyword<T: Copy>(&mut self, keywords: &[(&str, T)]) -> ParseResult<T> {
+        match self.try_keyword(keywords) {
+            Some(value) => Ok(value),
+            None => {
+                let names = keywords
+                    .iter()
+                    .map(|(keyword, _)| *keyword)
+                    .collect::<Vec<_>>();
+                self.error(format!("expected one of {}", names.join(", ")))
+            }
+        }
+    }
+
+    // `normal` and `auto` leave the alignment to the parent and the algorithm.
+    fn optional_keyword<T: Copy>(&mut self, keywords: &[(&str, T)]) -> ParseResult<Option<T>> {
+        if self.eat_ident("normal") || self.eat_ident("auto") {
+            Ok(None)
+        } else {
+            self.keyword(keywords).map(Some)
+        }
+    }
+
+    fn number(&mut self) -> ParseResult<f32> {
+        match self.peek() {
+            Some(TokenKind::Number { value, unit }) if unit.is_empty() => {
+                self.index += 1;
+                Ok(*value)
+            }
+            _ => self.error("expected a number"),
+        }
+    }
+
+    fn non_negative_number(&mut self) -> ParseResult<f32> {
+        let position = self.position();
+        let value = self.number()?;
+        if value < 0.0 {
+            return Err(ParseError::new(position, "expected a non-negative number"));
+        }
+        Ok(value)
+    }
+
+    fn integer(&mut self) -> ParseResult<i32> {
+        let position = self.position();
+        let value = self.number()?;
+        if value.fract() != 0.0 {
+            return Err(ParseError::new(position, "expected an integer"));
+        }
+        Ok(value as i32)
+    }
+
+    fn length(&mut self) -> ParseResult<f32> {
+        match self.length_percentage()? {
+            LengthPercentage::Length(value) => Ok(value),
+            LengthPercentage::Percent(_) => {
+                self.index -= 1;
+                self.error("expected a length")
+            }
+        }
+    }
+
+    fn length_percentage(&mut self) -> ParseResult<LengthPercentage> {
+        match self.peek() {
+            Some(TokenKind::Number { value, unit }) => {
+                let value = *value;
+                let result = match unit.as_str() {
+                    "px" => LengthPercentage::Length(value),
+                    "%" => LengthPercentage::Percent(value),
+                    "" if value == 0.0 => LengthPercentage::Length(0.0),
+                    "" => return self.error("expected a unit (px or %)"),
+                    unit => return self.error(format!("unsupported unit '{unit}'")),
+                };
+                self.index += 1;
+                Ok(result)
+            }
+            _ => self.error("expected a length or percentage"),
+        }
+    }
+
+    fn length_percentage_auto(&mut self) -> ParseResult<LengthPercentageAuto> {
+        if self.eat_ident("auto") {
+            return Ok(LengthPercentageAuto::Auto);
+        }
+        Ok(match self.length_percentage()? {
+            LengthPercentage::Length(value) => LengthPercentageAuto::Length(value),
+            LengthPercentage::Percent(value) => LengthPercentageAuto::Percent(value),
+        })
+    }
+
+    fn dimension(&mut self) -> ParseResult<Dimension> {
+        Ok(match self.length_percentage_auto()? {
+            LengthPercentageAuto::Length(value) => Dimension::Length(value),
+            LengthPercentageAuto::Percent(value) => Dimension::Percent(value),
+            LengthPercentageAuto::Auto => Dimension::Auto,
+        })
+    }
+
+    // The one to four value shorthand used by margin, padding, inset and border-width.
+    fn rect<T: Copy>(&mut self, parse: fn(&mut Self) -> ParseResult<T>) -> ParseResult<Rect<T>> {
+        let mut values = vec![parse(self)?];
+        while !self.is_empty() && values.len() < 4 {
+            values.push(parse(self)?);
+        }
+        let (top, right, bottom, left) = match values[..] {
+            [all] => (all, all, all, all),
+            [vertical, horizontal] => (vertical, horizontal, vertical, horizontal),
+            [top, horizontal, bottom] => (top, horizontal, bottom, horizontal),
+            [top, right, bottom, left] => (top, right, bottom, left),
+            _ => unreachable!(),
+        };
+        Ok(Rect {
+            top,
+            right,
+            bottom,
+            left,
+        })
+    }
+
+    fn aspect_ratio(&mut self) -> ParseResult<Option<f32>> {
+        if self.eat_ident("auto") {
+            return Ok(None);
+        }
+        let position = self.position();
+        let width = self.non_negative_number()?;
+        let height = if self.eat(TokenKind::Slash) {
+            self.non_negative_number()?
+        } else {
+            1.0
+        };
+        if height == 0.0 {
+            return Err(ParseError::new(position, "aspect ratio with a zero height"));
+        }
+        Ok(Some(width / height))
+    }
+
+    fn flex(&mut self) -> ParseResult<(f32, f32, Dimension)> {
+        if self.eat_ident("none") {
+            return Ok((0.0, 0.0, Dimension::Auto));
+        }
+        if self.eat_ident("auto") {
+            return Ok((1.0, 1.0, Dimension::Auto));
+        }
+
+        let mut grow = None;
+        let mut shrink = None;
+        let mut basis = None;
+        while !self.is_empty() {
+            match self.peek() {
+                Some(TokenKind::Number { unit, .. }) if unit.is_empty() && shrink.is_none() => {
+                    if grow.is_none() {
+                        grow = Some(self.non_negative_number()?);
+                    } else {
+                        shrink = Some(self.non_negative_number()?);
+                    }
+                }
+                _ if basis.is_none() => basis = Some(self.dimension()?),
+                _ => return self.error("unexpected value"),
+            }
+        }
+
+        // A bare number sets a zero basis, like `flex: 1` does in browsers.
+        let basis = basis.unwrap_or(if grow.is_some() {
+            Dimension::Length(0.0)
+        } else {
+            Dimension::Auto
+        });
+        Ok((grow.unwrap_or(1.0), shrink.unwrap_or(1.0), basis))
+    }
+
+    fn grid_auto_flow(&mut self) -> ParseResult<GridAutoFlow> {
+        let mut column = None;
+        let mut dense = false;
+        while !self.is_empty() {
+            if column.is_none() && self.eat_ident("row") {
+                column = Some(false);
+            } else if column.is_none() && self.eat_ident("column") {
+                column = Some(true);
+            } else if !dense && self.eat_ident("dense") {
+                dense = true;
+            } else {
+                return self.error("expected row, column or dense");
+            }
+        }
+        Ok(match (column.unwrap_or(false), dense) {
+            (false, false) => GridAutoFlow::Row,
+            (true, false) => GridAutoFlow::Column,
+            (false, true) => GridAutoFlow::RowDense,
+            (true, true) => GridAutoFlow::ColumnDense,
+        })
+    }
+
+    fn grid_placement(&mut self) -> ParseResult<GridPlacement> {
+        if self.eat_ident("auto") {
+            return Ok(GridPlacement::Auto);
+        }
+        let position = self.position();
+        if self.eat_ident("span") {
+            let span = self.integer()?;
+            return u16::try_from(span)
+                .ok()
+                .filter(|span| *span > 0)
+                .map(GridPlacement::from_span)
+                .ok_or_else(|| ParseError::new(position, "span must be a positive integer"));
+        }
+        let index = self.integer()?;
+        i16::try_from(index)
+            .ok()
+            .filter(|index| *index != 0)
+            .map(GridPlacement::from_line_index)
+            .ok_or_else(|| ParseError::new(position, "grid line must be a non-zero integer"))
+    }
+
+    fn grid_line(&mut self) -> ParseResult<Line<GridPlacement>> {
+        let start = self.grid_placement()?;
+        let end = if self.eat(TokenKind::Slash) {
+            self.grid_placement()?
+        } else {
+            GridPlacement::Auto
+        };
+        Ok(Line { start, end })
+    }
+
+    fn track_list(&mut self) -> ParseResult<Vec<TrackSizingFunction>> {
+        if self.eat_ident("none") {
+            return Ok(Vec::new());
+        }
+        let mut tracks = Vec::new();
+        while !self.is_empty() {
+            if let Some(TokenKind::Function(name)) = self.peek() {
+                if name == "repeat" {
+                    self.index += 1;
+                    tracks.push(self.repeat()?);
+                    continue;
+                }
+            }
+            tracks.push(TrackSizingFunction::Single(self.track_size()?));
+        }
+        Ok(tracks)
+    }
+
+    fn repeat(&mut self) -> ParseResult<TrackSizingFunction> {
+        let repetition = if self.eat_ident("auto-fill") {
+            GridTrackRepetition::AutoFill
+        } else if self.eat_ident("auto-fit") {
+            GridTrackRepetition::AutoFit
+        } else {
+            let position = self.position();
+            let count = self.integer()?;
+            u16::try_from(count)
+                .ok()
+                .filter(|count| *count > 0)
+                .map(GridTrackRepetition::Count)
+                .ok_or_else(|| {
+                    ParseError::new(position, "repeat count must be a positive integer")
+                })?
+        };
+        self.expect(TokenKind::Comma, "','")?;
+        let mut tracks = vec![self.track_size()?];
+        while !self.eat(TokenKind::CloseParen) {
+            if self.is_empty() {
+                return self.error("expected ')'");
+            }
+            tracks.push(self.track_size()?);
+        }
+        Ok(TrackSizingFunction::Repeat(repetition, tracks))
+    }
+
+    fn track_sizes(&mut self) -> ParseResult<Vec<NonRepeatedTrackSizingFunction>> {
+        let mut tracks = Vec::new();
+        while !self.is_empty() {
+            tracks.push(self.track_size()?);
+        }
+        Ok(tracks)
+    }
+
+    fn track_size(&mut self) -> ParseResult<NonRepeatedTrackSizingFunction> {
+        match self.peek() {
+            Some(TokenKind::Function(name)) if name == "minmax" => {
+                self.index += 1;
+                let min = self.min_track_sizing_function()?;
+                self.expect(TokenKind::Comma, "','")?;
+                let max = self.max_track_sizing_function()?;
+                self.expect(TokenKind::CloseParen, "')'")?;
+                Ok(NonRepeatedTrackSizingFunction { min, max })
+            }
+            Some(TokenKind::Function(name)) if name == "fit-content" => {
+                Ok(NonRepeatedTrackSizingFunction {
+                    min: MinTrackSizingFunction::Auto,
+                    max: self.max_track_sizing_function()?,
+                })
+            }
+            Some(TokenKind::Number { unit, .. }) if unit == "fr" => {
+                Ok(NonRepeatedTrackSizingFunction {
+                    min: MinTrackSizingFunction::Auto,
+                    max: self.max_track_sizing_function()?,
+                })
+            }
+            _ => {
+                let min = self.min_track_sizing_function()?;
+                Ok(NonRepeatedTrackSizingFunction {
+                    min,
+                    max: max_track_sizing_function(min),
+                })
+            }
+        }
+    }
+
+    fn min_track_sizing_function(&mut self) -> ParseResult<MinTrackSizingFunction> {
+        if self.eat_ident("min-content") {
+            Ok(MinTrackSizingFunction::MinContent)
+        } else if self.eat_ident("max-content") {
+            Ok(MinTrackSizingFunction::MaxContent)
+        } else if self.eat_ident("auto") {
+            Ok(MinTrackSizingFunction::Auto)
+        } else if matches!(self.peek(), Some(TokenKind::Number { unit, .. }) if unit == "fr") {
+            self.error("fr units are only allowed as a maximum track size")
+        } else {
+            Ok(MinTrackSizingFunction::Fixed(self.length_percentage()?))
+        }
+    }
+
+    // Unlike CSS, this takes fit-content() as the maximum of a minmax() too, as
+    // taffy does, so that every track size has a CSS form.
+    fn max_track_sizing_function(&mut self) -> ParseResult<MaxTrackSizingFunction> {
+        if matches!(self.peek(), Some(TokenKind::Function(name)) if name == "fit-content") {
+            self.index += 1;
+            let limit = self.length_percentage()?;
+            self.expect(TokenKind::CloseParen, "')'")?;
+            return Ok(MaxTrackSizingFunction::FitContent(limit));
+        }
+        if let Some(TokenKind::Number { value, unit }) = self.peek() {
+            if unit == "fr" {
+                if *value < 0.0 {
+                    return self.error("expected a non-negative flex factor");
+                }
+                self.index += 1;
+                return Ok(MaxTrackSizingFunction::Fraction(*value));
+            }
+        }
+        Ok(max_track_sizing_function(self.min_track_sizing_function()?))
+    }
+}
+
+fn max_track_sizing_function(min: MinTrackSizingFunction) -> MaxTrackSizingFunction {
+    match min {
+        MinTrackSizingFunction::Fixed(value) => MaxTrackSizingFunction::Fixed(value),
+        MinTrackSizingFunction::MinContent => MaxTrackSizingFunction::MinContent,
+        MinTrackSizingFunction::MaxContent => MaxTrackSizingFunction::MaxContent,
+        MinTrackSizingFunction::Auto => MaxTrackSizingFunction::Auto,
+    }
+}
+
+fn keyword_name<T: PartialEq>(keywords: &[(&'static str, T)], value: T) -> &'static str {
+    keywords
+        .iter()
+        .find(|(_, keyword)| *keyword == value)
+        .map_or("", |(name, _)| name)
+}
+
+// A value written as CSS, or the number that can't be.
+type CssResult = Result<String, f32>;
+
+fn join_css<T>(values: &[T], to_css: fn(&T) -> CssResult) -> CssResult {
+    Ok(values
+        .iter()
+        .map(to_css)
+        .collect::<Result<Vec<_>, _>>()?
+        .join(" "))
+}
+
+// Display gives the shortest decimal that parses back to the same f32.
+fn number_css(value: f32) -> CssResult {
+    if value.is_finite() {
+        Ok(value.to_string())
+    } else {
+        Err(value)
+    }
+}
+
+fn length_css(value: f32) -> CssResult {
+    Ok(format!("{}px", number_css(value)?))
+}
+
+// The shortest percentage that parses back to exactly `value`. Multiplying by
+// 100 in f32 isn't enough, as it turns 0.1 into 10.000001%.
+fn percent_css(value: f32) -> CssResult {
+    number_css(value)?;
+    let percent = f64::from(value) * 100.0;
+    let css = (0..=9)
+        .map(|decimals| format!("{percent:.decimals$}"))
+        .find(|css| percent_fraction(css) == value)
+        .unwrap_or_else(|| percent.to_string());
+    Ok(format!("{css}%"))
+}
+
+fn length_percentage_css(value: LengthPercentage) -> CssResult {
+    match value {
+        LengthPercentage::Length(value) => length_css(value),
+        LengthPercentage::Percent(value) => percent_css(value),
+    }
+}
+
+fn length_percentage_auto_css(value: LengthPercentageAuto) -> CssResult {
+    match value {
+        LengthPercentageAuto::Length(value) => length_css(value),
+        LengthPercentageAuto::Percent(value) => percent_css(value),
+        LengthPercentageAuto::Auto => Ok("auto".to_owned()),
+    }
+}
+
+fn dimension_css(value: Dimension) -> CssResult {
+    match value {
+        Dimension::Length(value) => length_css(value),
+        Dimension::Percent(value) => percent_css(value),
+        Dimension::Auto => Ok("auto".to_owned()),
+    }
+}
+
+// Uses the shortest of the one to four value forms.
+fn rect_css<T: PartialEq + Copy>(rect: Rect<T>, to_css: fn(T) -> CssResult) -> CssResult {
+    let Rect {
+        top,
+        right,
+        bottom,
+        left,
+    } = rect;
+    let values = if left != right {
+        vec![top, right, bottom, left]
+    } else if top != bottom {
+        vec![top, right, bottom]
+    } else if top != right {
+        vec![top, right]
+    } else {
+        vec![top]
+    };
+    Ok(values
+        .into_iter()
+        .map(to_css)
+        .collect::<Result<Vec<_>, _>>()?
+        .join(" "))
+}
+
+fn grid_placement_css(placement: GridPlacement) -> String {
+    match placement {
+        GridPlacement::Auto => "auto".to_owned(),
+        GridPlacement::Line(line) => line.as_i16().to_string(),
+        GridPlacement::Span(span) => format!("span {span}"),
+    }
+}
+
+fn grid_line_css(line: Line<GridPlacement>) -> String {
+    match line.end {
+        GridPlacement::Auto => grid_placement_css(line.start),
+        end => format!(
+            "{} / {}",
+            grid_placement_css(line.start),
+            grid_placement_css(end)
+        ),
+    }
+}
+
+fn min_track_sizing_function_css(min: MinTrackSizingFunction) -> CssResult {
+    match min {
+        MinTrackSizingFunction::Fixed(value) => length_percentage_css(value),
+        MinTrackSizingFunction::MinContent => Ok("min-content".to_owned()),
+        MinTrackSizingFunction::MaxContent => Ok("max-content".to_owned()),
+        MinTrackSizingFunction::Auto => Ok("auto".to_owned()),
+    }
+}
+
+fn max_track_sizing_function_css(max: MaxTrackSizingFunction) -> CssResult {
+    match max {
+        MaxTrackSizingFunction::FitContent(value) => {
+            Ok(format!("fit-content({})", length_percentage_css(value)?))
+        }
+        MaxTrackSizingFunction::Fraction(value) => Ok(format!("{}fr", number_css(value)?)),
+        MaxTrackSizingFunction::Fixed(value) => length_percentage_css(value),
+        MaxTrackSizingFunction::MinContent => Ok("min-content".to_owned()),
+        MaxTrackSizingFunction::MaxContent => Ok("max-content".to_owned()),
+        MaxTrackSizingFunction::Auto => Ok("auto".to_owned()),
+    }
+}
+
+fn track_size_css(track: &NonRepeatedTrackSizingFunction) -> CssResult {
+    match (track.min, track.max) {
+        (MinTrackSizingFunction::Auto, max @ MaxTrackSizingFunction::Fraction(_))
+        | (MinTrackSizingFunction::Auto, max @ MaxTrackSizingFunction::FitContent(_)) => {
+            max_track_sizing_function_css(max)
+        }
+        (min, max) if max_track_sizing_function(min) == max => min_track_sizing_function_css(min),
+        (min, max) => Ok(format!(
+            "minmax({}, {})",
+            min_track_sizing_function_css(min)?,
+            max_track_sizing_function_css(max)?
+        )),
+    }
+}
+
+fn track_sizing_function_css(track: &TrackSizingFunction) -> CssResult {
+    match track {
+        TrackSizingFunction::Single(track) => track_size_css(track),
+        TrackSizingFunction::Repeat(repetition, tracks) => {
+            let repetition = match repetition {
+                GridTrackRepetition::AutoFill => "auto-fill".to_owned(),
+                GridTrackRepetition::AutoFit => "auto-fit".to_owned(),
+                GridTrackRepetition::Count(count) => count.to_string(),
+            };
+            Ok(format!(
+                "repeat({repetition}, {})",
+                join_css(tracks, track_size_css)?
+            ))
+        }
+    }
+}
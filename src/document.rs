@@ -0,0 +1,293 @@
+use std::collections::HashMap;
+
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use taffy::{Layout, Point, Rect, Size};
+
+use crate::{Convert, NodeContext, StyleData, TaffyError, TaffyResult};
+
+const VERSION: u32 = 1;
+const BINARY_VERSION: u32 = 2;
+const BINARY_MAGIC: &[u8; 4] = b"TFYB";
+
+// A whole tree flattened into a list of nodes in pre-order, with the root at
+// index 0. Keeping it flat avoids recursion limits on deeply nested trees.
+//
+// This is also the JSON schema of to_json and from_json, which belongs to this
+// crate rather than to taffy: styles are written as their CSS declarations and
+// layouts through the types below, so a taffy upgrade can't change the format.
+#[derive(Serialize, Deserialize)]
+pub(crate) struct TreeDocument {
+    pub version: u32,
+    pub nodes: Vec<NodeDocument>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub(crate) struct NodeDocument {
+    #[serde(with = "css_style")]
+    pub style: taffy::Style,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub context: Option<NodeContext>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub layout: Option<LayoutDocument>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub children: Vec<usize>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub(crate) struct LayoutDocument {
+    order: u32,
+    location: PointDocument,
+    size: SizeDocument,
+    content_size: SizeDocument,
+    scrollbar_size: SizeDocument,
+    border: RectDocument,
+    padding: RectDocument,
+}
+
+#[derive(Serialize, Deserialize)]
+struct PointDocument {
+    x: f32,
+    y: f32,
+}
+
+#[derive(Serialize, Deserialize)]
+struct SizeDocument {
+    width: f32,
+    height: f32,
+}
+
+#[derive(Serialize, Deserialize)]
+struct RectDocument {
+    left: f32,
+    right: f32,
+    top: f32,
+    bottom: f32,
+}
+
+// The binary form of a TreeDocument: BINARY_MAGIC, the version as a
+// little-endian u32 and then a BinarySnapshot encoded as MessagePack, with
+// structs written as arrays and every distinct style stored once. Version 1
+// stored styles as CSS, which every import had to parse again; version 2
+// stores their StyleData. The version comes first so that a newer snapshot is
+// rejected before anything is decoded. Any change to these structs, or to how
+// taffy serializes the types in StyleData, needs a BINARY_VERSION bump and a
+// reader for the previous version, which tests/snapshots holds the crate to.
+#[derive(Serialize, Deserialize)]
+struct BinarySnapshot<S> {
+    styles: Vec<S>,
+    nodes: Vec<BinaryNode>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct BinaryNode {
+    style: u32,
+    context: Option<NodeContext>,
+    children: Vec<u32>,
+}
+
+mod css_style {
+    use serde::{de::Error as _, ser::Error as _, Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(
+        style: &taffy::Style,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&crate::css::to_css(style).map_err(S::Error::custom)?)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<taffy::Style, D::Error> {
+        super::parse_style(&String::deserialize(deserializer)?).map_err(D::Error::custom)
+    }
+}
+
+fn parse_style(css: &str) -> TaffyResult<taffy::Style> {
+    crate::css::apply_css(&taffy::Style::DEFAULT, css)
+}
+
+impl From<&Layout> for LayoutDocument {
+    fn from(layout: &Layout) -> Self {
+        Self {
+            order: layout.order,
+            location: layout.location.into(),
+            size: layout.size.into(),
+            content_size: layout.content_size.into(),
+            scrollbar_size: layout.scrollbar_size.into(),
+            border: layout.border.into(),
+            padding: layout.padding.into(),
+        }
+    }
+}
+
+impl From<Point<f32>> for PointDocument {
+    fn from(Point { x, y }: Point<f32>) -> Self {
+        Self { x, y }
+    }
+}
+
+impl From<Size<f32>> for SizeDocument {
+    fn from(Size { width, height }: Size<f32>) -> Self {
+        Self { width, height }
+    }
+}
+
+impl From<Rect<f32>> for RectDocument {
+    fn from(
+        Rect {
+            left,
+            right,
+            top,
+            bottom,
+        }: Rect<f32>,
+    ) -> Self {
+        Self {
+            left,
+            right,
+            top,
+            bottom,
+        }
+    }
+}
+
+impl TreeDocument {
+    pub fn new(nodes: Vec<NodeDocument>) -> Self {
+        Self {
+            version: VERSION,
+            nodes,
+        }
+    }
+
+    pub fn from_json(json: &str) -> TaffyResult<Self> {
+        let document: Self =
+            serde_json::from_str(json).map_err(|error| invalid(error.to_string()))?;
+        document.validate()?;
+        Ok(document)
+    }
+
+    pub fn to_json(&self) -> TaffyResult<String> {
+        serde_json::to_string(self).map_err(|error| invalid(error.to_string()))
+    }
+
+    pub fn from_binary(data: &[u8]) -> TaffyResult<Self> {
+        let data = data
+            .strip_prefix(BINARY_MAGIC)
+            .ok_or_else(|| invalid("not a binary tree snapshot".to_owned()))?;
+        let (version, body) = data
+            .split_first_chunk()
+            .ok_or_else(|| invalid("truncated binary tree snapshot".to_owned()))?;
+        match u32::from_le_bytes(*version) {
+            1 => Self::from_snapshot(decode::<String>(body)?, |css| {
+                parse_style(&css).map_err(|error| invalid(error.to_string()))
+            }),
+            2 => Self::from_snapshot(decode::<StyleData>(body)?, |data| Ok(data.convert())),
+            version => Err(invalid(format!("unsupported version {version}"))),
+        }
+    }
+
+    fn from_snapshot<S>(
+        snapshot: BinarySnapshot<S>,
+        style: impl Fn(S) -> TaffyResult<taffy::Style>,
+    ) -> TaffyResult<Self> {
+        let styles = snapshot
+            .styles
+            .into_iter()
+            .map(style)
+            .collect::<TaffyResult<Vec<_>>>()?;
+        let nodes = snapshot
+            .nodes
+            .into_iter()
+            .enumerate()
+            .map(|(index, node)| {
+                let style = styles.get(node.style as usize).ok_or_else(|| {
+                    invalid(format!("node {index} has invalid style {}", node.style))
+                })?;
+                Ok(NodeDocument {
+                    style: style.clone(),
+                    context: node.context,
+                    layout: None,
+                    children: node
+                        .children
+                        .into_iter()
+                        .map(|child| child as usize)
+                        .collect(),
+                })
+            })
+            .collect::<TaffyResult<_>>()?;
+        let document = Self::new(nodes);
+        document.validate()?;
+        Ok(document)
+    }
+
+    pub fn to_binary(&self) -> TaffyResult<Vec<u8>> {
+        let mut snapshot = BinarySnapshot {
+            styles: Vec::new(),
+            nodes: Vec::new(),
+        };
+        // Styles hold floats, so they're told apart by their encoding.
+        let mut style_indices = HashMap::new();
+        for node in &self.nodes {
+            let data: StyleData = node.style.convert();
+            let encoded = rmp_serde::to_vec(&data).map_err(|error| invalid(error.to_string()))?;
+            let style = *style_indices.entry(encoded).or_insert_with(|| {
+                snapshot.styles.push(data);
+                snapshot.styles.len() as u32 - 1
+            });
+            snapshot.nodes.push(BinaryNode {
+                style,
+                context: node.context.clone(),
+                children: node.children.iter().map(|&child| child as u32).collect(),
+            });
+        }
+        let mut data = BINARY_MAGIC.to_vec();
+        data.extend(BINARY_VERSION.to_le_bytes());
+        rmp_serde::encode::write(&mut data, &snapshot)
+            .map_err(|error| invalid(error.to_string()))?;
+        Ok(data)
+    }
+
+    // Checks that the nodes form a single tree rooted at index 0, so that
+    // building it can't fail halfway and leave stray nodes behind.
+    fn validate(&self) -> TaffyResult<()> {
+        check_version(self.version)?;
+        if self.nodes.is_empty() {
+            return Err(invalid("document has no nodes".to_owned()));
+        }
+        let mut has_parent = vec![false; self.nodes.len()];
+        for (index, node) in self.nodes.iter().enumerate() {
+            for &child in &node.children {
+                // Children always come after their parent in pre-order, which
+                // also rules out cycles.
+                if child <= index || child >= self.nodes.len() {
+                    return Err(invalid(format!("node {index} has invalid child {child}")));
+                }
+                if std::mem::replace(&mut has_parent[child], true) {
+                    return Err(invalid(format!("node {child} has more than one parent")));
+                }
+            }
+        }
+        match has_parent.iter().skip(1).position(|has_parent| !has_parent) {
+            Some(index) => Err(invalid(format!(
+                "node {} is not reachable from the root",
+                index + 1
+            ))),
+            None => Ok(()),
+        }
+    }
+}
+
+// JSON documents are still at version 1, so there's nothing older to convert.
+fn check_version(version: u32) -> TaffyResult<()> {
+    if version == 0 || version > VERSION {
+        return Err(invalid(format!("unsupported version {version}")));
+    }
+    Ok(())
+}
+
+fn decode<S: DeserializeOwned>(body: &[u8]) -> TaffyResult<BinarySnapshot<S>> {
+    rmp_serde::from_slice(body).map_err(|error| invalid(error.to_string()))
+}
+
+fn invalid(reason: String) -> TaffyError {
+    TaffyError::InvalidDocument { reason }
+}
@@ -1,4 +1,4 @@
-use std::sync::PoisonError;
+use std::{any::Any, sync::PoisonError};
 
 use taffy::NodeId;
 use thiserror::Error;
@@ -7,20 +7,84 @@ pub type TaffyResult<T> = Result<T, TaffyError>;
 
 #[derive(Error, Debug)]
 pub enum TaffyError {
-    #[error("lock poisoned")]
+    #[error("lock poisoned by a panic during an earlier call, until recover() is called")]
     Poison,
-    #[error("child index out of bounds(parent: {parent:?}, child_index: {child_index}, child_count: {child_count})")]
+    #[error("child index {child_index} is out of bounds for parent {parent:?} with {child_count} children")]
     ChildIndexOutOfBounds {
         parent: NodeId,
         child_index: u64,
         child_count: u64,
     },
-    #[error("child index out of bounds (node_id: {node_id:?})")]
+    #[error("parent node {node_id:?} is not in the tree")]
     InvalidParentNode { node_id: NodeId },
-    #[error("child index out of bounds (node_id: {node_id:?})")]
+    #[error("child node {node_id:?} is not in the tree")]
     InvalidChildNode { node_id: NodeId },
-    #[error("child index out of bounds (node_id: {node_id:?})")]
+    #[error("node {node_id:?} is not in the tree")]
     InvalidInputNode { node_id: NodeId },
+    #[error("font data could not be parsed")]
+    InvalidFontData,
+    #[error("font {font} is not loaded in the font collection")]
+    InvalidFont { font: u32 },
+    #[error("invalid value {value} for style property {property}")]
+    InvalidStyleValue { property: String, value: f32 },
+    #[error("measure function failed for node {node_id:?}: {reason}")]
+    MeasureFailed { node_id: NodeId, reason: String },
+    #[error(
+        "tree is locked by a layout computation on this thread (accessed from a measure function?)"
+    )]
+    LockContention,
+    #[error("invalid CSS at {line}:{column}: {reason}")]
+    InvalidCss {
+        line: u32,
+        column: u32,
+        reason: String,
+    },
+    #[error("invalid tree document: {reason}")]
+    InvalidDocument { reason: String },
+    #[error("node {child:?} is not a child of {parent:?}")]
+    NotAChild { parent: NodeId, child: NodeId },
+    #[error("node {node_id:?} is listed more than once as a child")]
+    DuplicateChild { node_id: NodeId },
+    #[error("node {child:?} can't be a child of {parent:?}, which is inside it")]
+    CyclicChild { parent: NodeId, child: NodeId },
+    #[error("internal error: {reason}")]
+    Internal { reason: String },
+}
+
+// The readable text of an error, which the bindings otherwise only show as a
+// list of its fields.
+pub fn error_message(error: TaffyError) -> String {
+    error.to_string()
+}
+
+impl TaffyError {
+    pub(crate) fn from_panic(payload: Box<dyn Any + Send>) -> Self {
+        Self::Internal {
+            reason: format!("taffy panicked: {}", panic_message(&*payload)),
+        }
+    }
+}
+
+pub(crate) fn panic_message(payload: &(dyn Any + Send)) -> String {
+    payload
+        .downcast_ref::<&str>()
+        .map(|message| message.to_string())
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| "unknown panic".to_owned())
+}
+
+#[derive(Error, Debug)]
+pub enum MeasureError {
+    #[error("{reason}")]
+    Failed { reason: String },
+}
+
+impl From<uniffi::UnexpectedUniFFICallbackError> for MeasureError {
+    fn from(error: uniffi::UnexpectedUniFFICallbackError) -> Self {
+        Self::Failed {
+            reason: error.reason,
+        }
+    }
 }
 
 impl<T> From<PoisonError<T>> for TaffyError {
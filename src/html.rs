@@ -0,0 +1,345 @@
+use taffy::{NodeId, Size};
+
+use crate::{
+    document::{NodeDocument, TreeDocument},
+    AvailableSpace, MeasureError, MeasureFunction, NodeContext, TaffyError, TaffyResult,
+};
+
+// Elements that never have content or a closing tag.
+const VOID_ELEMENTS: &[&str] = &[
+    "area", "base", "br", "col", "embed", "hr", "img", "input", "link", "meta", "source", "track",
+    "wbr",
+];
+// Elements whose content is not markup.
+const RAW_TEXT_ELEMENTS: &[&str] = &["script", "style", "title", "textarea"];
+
+const ZERO_WIDTH_SPACE: char = '\u{200B}';
+// Every glyph of the Ahem font the fixtures are rendered with is a 1em
+// square, at the fixtures' font size of 10px.
+const AHEM_SIZE: f32 = 10.0;
+
+// A fixture parsed into a tree document, along with the text of the leaf
+// divs that have some, by document index.
+pub(crate) struct Fixture {
+    pub document: TreeDocument,
+    pub texts: Vec<(usize, String)>,
+}
+
+struct Element {
+    style: taffy::Style,
+    text: String,
+    children: Vec<usize>,
+    is_root: bool,
+}
+
+// Builds a tree from the divs of a fixture in the format of taffy's test
+// suite, rooted at `#test-root` or else the first div. Other elements are
+// transparent; they only contribute their divs.
+pub(crate) fn parse_fixture(html: &str) -> TaffyResult<Fixture> {
+    let mut elements: Vec<Element> = Vec::new();
+    // Open elements, with the index of those that are divs.
+    let mut open: Vec<(String, Option<usize>)> = Vec::new();
+    let mut position = 0;
+
+    while position < html.len() {
+        let rest = &html[position..];
+        if let Some(comment) = rest.strip_prefix("<!--") {
+            position += 4 + comment.find("-->").map_or(comment.len(), |end| end + 3);
+        } else if rest.starts_with("<!") || rest.starts_with("<?") {
+            position += rest.find('>').map_or(rest.len(), |end| end + 1);
+        } else if let Some(tag) = rest.strip_prefix("</") {
+            let end = tag.find('>').map_or(tag.len(), |end| end + 1);
+            let name = tag[..end].trim_end_matches('>').trim().to_ascii_lowercase();
+            // Tolerate unbalanced markup by closing everything up to the
+            // matching open element, if there is one.
+            if let Some(index) = open.iter().rposition(|(open, _)| *open == name) {
+                open.truncate(index);
+            }
+            position += 2 + end;
+        } else if rest.starts_with('<') && rest[1..].starts_with(|c: char| c.is_ascii_alphabetic())
+        {
+            let tag = parse_tag(html, position)?;
+            position = tag.end;
+            if RAW_TEXT_ELEMENTS.contains(&tag.name.as_str()) {
+                let close = format!("</{}", tag.name);
+                position += html[position..]
+                    .to_ascii_lowercase()
+                    .find(&close)
+                    .unwrap_or(html.len() - position);
+                continue;
+            }
+            let mut index = None;
+            if tag.name == "div" {
+                let style = match tag.style {
+                    Some((start, end)) => {
+                        crate::css::apply_embedded_css(&taffy::Style::DEFAULT, html, start, end)?
+                    }
+                    None => taffy::Style::DEFAULT,
+                };
+                let child = elements.len();
+                if let Some(parent) = open.iter().rev().find_map(|(_, index)| *index) {
+                    elements[parent].children.push(child);
+                }
+                index = Some(child);
+                elements.push(Element {
+                    style,
+                    text: String::new(),
+                    children: Vec::new(),
+                    is_root: tag.id.as_deref() == Some("test-root"),
+                });
+            }
+            if !tag.self_closing && !VOID_ELEMENTS.contains(&tag.name.as_str()) {
+                open.push((tag.name, index));
+            }
+        } else {
+            // A stray '<' is text too.
+            let end = match rest.strip_prefix('<') {
+                Some(text) => text.find('<').map_or(rest.len(), |end| end + 1),
+                None => rest.find('<').unwrap_or(rest.len()),
+            };
+            if let Some(div) = open.iter().rev().find_map(|(_, index)| *index) {
+                elements[div].text.push_str(&decode_entities(&rest[..end]));
+            }
+            position += end;
+        }
+    }
+
+    let root = elements
+        .iter()
+        .position(|element| element.is_root)
+        .or((!elements.is_empty()).then_some(0))
+        .ok_or_else(|| TaffyError::InvalidDocument {
+            reason: "fixture has no div elements".to_owned(),
+        })?;
+    // Divs are collected in pre-order, so the root's subtree is the run of
+    // elements up to the first one past its last descendant.
+    let mut end = root + 1;
+    while let Some(&last) = elements[end - 1].children.last() {
+        end = last + 1;
+    }
+
+    let mut texts = Vec::new();
+    let nodes = elements
+        .drain(root..end)
+        .enumerate()
+        .map(|(index, element)| {
+            let text = collapse_whitespace(&element.text);
+            if element.children.is_empty() && !text.is_empty() {
+                texts.push((index, text));
+            }
+            NodeDocument {
+                style: element.style,
+                context: None,
+                layout: None,
+                children: element.children.iter().map(|child| child - root).collect(),
+            }
+        })
+        .collect();
+    Ok(Fixture {
+        document: TreeDocument::new(nodes),
+        texts,
+    })
+}
+
+struct Tag {
+    name: String,
+    id: Option<String>,
+    // Byte range of the style attribute's value.
+    style: Option<(usize, usize)>,
+    self_closing: bool,
+    end: usize,
+}
+
+fn parse_tag(html: &str, start: usize) -> TaffyResult<Tag> {
+    let bytes = html.as_bytes();
+    let mut position = start + 1;
+    let name_end = position
+        + html[position..]
+            .find(|c: char| c.is_ascii_whitespace() || c == '>' || c == '/')
+            .unwrap_or(html.len() - position);
+    let mut tag = Tag {
+        name: html[position..name_end].to_ascii_lowercase(),
+        id: None,
+        style: None,
+        self_closing: false,
+        end: html.len(),
+    };
+    position = name_end;
+
+    loop {
+        while position < html.len() && bytes[position].is_ascii_whitespace() {
+            position += 1;
+        }
+        match bytes.get(position) {
+            None => return Err(invalid_fixture(html, start, "unterminated tag")),
+            Some(b'>') => {
+                tag.end = position + 1;
+                return Ok(tag);
+            }
+            Some(b'/') => {
+                tag.self_closing = true;
+                position += 1;
+                continue;
+            }
+            Some(_) => {}
+        }
+
+        let name_start = position;
+        while position < html.len()
+            && !bytes[position].is_ascii_whitespace()
+            && !matches!(bytes[position], b'=' | b'>' | b'/')
+        {
+            position += 1;
+        }
+        let name = html[name_start..position].to_ascii_lowercase();
+        while position < html.len() && bytes[position].is_ascii_whitespace() {
+            position += 1;
+        }
+        if bytes.get(position) != Some(&b'=') {
+            continue;
+        }
+        position += 1;
+        while position < html.len() && bytes[position].is_ascii_whitespace() {
+            position += 1;
+        }
+
+        let (value_start, value_end) = match bytes.get(position) {
+            Some(&quote @ (b'"' | b'\'')) => {
+                let value_start = position + 1;
+                let value_end = html[value_start..]
+                    .find(quote as char)
+                    .map(|end| value_start + end)
+                    .ok_or_else(|| invalid_fixture(html, position, "unterminated attribute"))?;
+                position = value_end + 1;
+                (value_start, value_end)
+            }
+            _ => {
+                let value_start = position;
+                while position < html.len()
+                    && !bytes[position].is_ascii_whitespace()
+                    && bytes[position] != b'>'
+                {
+                    position += 1;
+                }
+                (value_start, position)
+            }
+        };
+        match name.as_str() {
+            "id" => tag.id = Some(decode_entities(&html[value_start..value_end])),
+            "style" => tag.style = Some((value_start, value_end)),
+            _ => {}
+        }
+    }
+}
+
+fn invalid_fixture(html: &str, position: usize, message: &str) -> TaffyError {
+    let before = &html[..position];
+    let line = before.matches('\n').count() + 1;
+    let column = before[before.rfind('\n').map_or(0, |index| index + 1)..]
+        .chars()
+        .count()
+        + 1;
+    TaffyError::InvalidDocument {
+        reason: format!("{message} at {line}:{column}"),
+    }
+}
+
+fn decode_entities(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find('&') {
+        result.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let decoded = rest.find(';').and_then(|end| {
+            let entity = &rest[1..end];
+            let character = match entity {
+                "amp" => Some('&'),
+                "lt" => Some('<'),
+                "gt" => Some('>'),
+                "quot" => Some('"'),
+                "apos" => Some('\''),
+                "nbsp" => Some('\u{A0}'),
+                _ => entity
+                    .strip_prefix("#x")
+                    .or_else(|| entity.strip_prefix("#X"))
+                    .map(|hex| u32::from_str_radix(hex, 16))
+                    .or_else(|| entity.strip_prefix('#').map(str::parse))
+                    .and_then(Result::ok)
+                    .and_then(char::from_u32),
+            };
+            character.map(|character| (character, end + 1))
+        });
+        match decoded {
+            Some((character, length)) => {
+                result.push(character);
+                rest = &rest[length..];
+            }
+            None => {
+                result.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    result.push_str(rest);
+    result
+}
+
+fn collapse_whitespace(text: &str) -> String {
+    text.split_ascii_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+// Measures text the way taffy's generated tests do: Ahem glyphs, with line
+// breaks allowed only at zero width spaces.
+pub(crate) struct FixtureText(pub String);
+
+impl MeasureFunction for FixtureText {
+    fn measure(
+        &self,
+        known_size: Size<Option<f32>>,
+        avaliable_space: Size<AvailableSpace>,
+        _node: NodeId,
+        _context: Option<NodeContext>,
+    ) -> Result<Size<f32>, MeasureError> {
+        if let Size {
+            width: Some(width),
+            height: Some(height),
+        } = known_size
+        {
+            return Ok(Size { width, height });
+        }
+
+        let words: Vec<usize> = self
+            .0
+            .split(ZERO_WIDTH_SPACE)
+            .map(|word| word.chars().count())
+            .collect();
+        let min_line_length = words.iter().copied().max().unwrap_or(0);
+        let max_line_length: usize = words.iter().sum();
+        let width = known_size
+            .width
+            .unwrap_or_else(|| match avaliable_space.width {
+                AvailableSpace::MinContent => min_line_length as f32 * AHEM_SIZE,
+                AvailableSpace::MaxContent => max_line_length as f32 * AHEM_SIZE,
+                AvailableSpace::Definite { value } => value
+                    .min(max_line_length as f32 * AHEM_SIZE)
+                    .max(min_line_length as f32 * AHEM_SIZE),
+            });
+        let height = known_size.height.unwrap_or_else(|| {
+            let line_capacity = (width / AHEM_SIZE).floor() as usize;
+            let mut line_count = 1;
+            let mut line_length = 0;
+            for &word in &words {
+                if line_length + word > line_capacity {
+                    if line_length > 0 {
+                        line_count += 1;
+                    }
+                    line_length = word;
+                } else {
+                    line_length += word;
+                }
+            }
+            line_count as f32 * AHEM_SIZE
+        });
+        Ok(Size { width, height })
+    }
+}
@@ -1,10 +1,21 @@
+// The dependency is renamed in Cargo.toml; within the crate it's still `taffy`.
+extern crate taffy_core as taffy;
+
 uniffi::include_scaffolding!("taffy");
 
+mod css;
+mod document;
 mod error;
+mod html;
 mod style;
+#[cfg(feature = "text")]
+mod text;
 mod tree;
+mod validation;
+
+pub use crate::{error::*, style::*, tree::*, validation::StyleValidation};
 
-pub use crate::{error::*, style::*, tree::*};
+use serde::{Deserialize, Serialize};
 use taffy::{
     style_helpers::{TaffyAuto, TaffyGridLine, TaffyGridSpan},
     Line, MinMax, Point, Rect, Size,
@@ -14,6 +25,9 @@ pub use taffy::{
     Overflow, Position,
 };
 
+#[cfg(feature = "text")]
+pub use crate::text::*;
+
 pub type PointFloat = Point<f32>;
 pub type SizeFloat = Size<f32>;
 pub type RectFloat = Rect<f32>;
@@ -29,6 +43,7 @@ pub type SizeLengthPercentage = Size<LengthPercentage>;
 
 macro_rules! uniffi_enum {
     (pub enum $name:ident { $( $variant:ident $( { $( $field_name:ident : $field_type:ty ),* } )? ),* } ) => {
+        #[derive(Serialize, Deserialize)]
         pub enum $name {
             $(
                 $variant $( { $( $field_name: $field_type ),* } )*
@@ -128,6 +143,7 @@ uniffi_enum! {
     }
 }
 
+#[derive(Serialize, Deserialize)]
 pub enum GridPlacement {
     Line { index: i16 },
     Span { span: u16 },
@@ -137,6 +153,9 @@ pub enum GridPlacement {
 impl Convert<taffy::GridPlacement> for GridPlacement {
     fn convert(&self) -> taffy::GridPlacement {
         match self {
+            // Line 0 and span 0 are invalid in CSS, and taffy panics when laying
+            // them out, so they're treated as auto like an invalid declaration.
+            Self::Line { index: 0 } | Self::Span { span: 0 } => taffy::GridPlacement::AUTO,
             Self::Line { index } => taffy::GridPlacement::from_line_index(index.convert()),
             Self::Span { span } => taffy::GridPlacement::from_span(span.convert()),
             Self::Auto => taffy::GridPlacement::AUTO,
@@ -150,12 +169,22 @@ impl Convert<GridPlacement> for taffy::GridPlacement {
             Self::Line(index) => GridPlacement::Line {
                 index: index.as_i16(),
             },
-            Self::Span(span) => GridPlacement::Span { span: span.convert() },
+            Self::Span(span) => GridPlacement::Span {
+                span: span.convert(),
+            },
             Self::Auto => GridPlacement::Auto,
         }
     }
 }
 
+// Contexts are plain values: hosts keep their own objects and store a key to
+// them as a handle.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum NodeContext {
+    Handle { value: u64 },
+    Bytes { value: Vec<u8> },
+}
+
 pub trait Convert<T> {
     fn convert(&self) -> T;
 }
@@ -165,7 +194,7 @@ where
     T: Convert<U>,
 {
     fn convert(&self) -> Vec<U> {
-        self.into_iter().map(Convert::convert).collect()
+        self.iter().map(Convert::convert).collect()
     }
 }
 
@@ -250,17 +279,8 @@ where
     }
 }
 
-impl UniffiCustomTypeConverter for NodeId {
-    type Builtin = u64;
-
-    fn into_custom(val: Self::Builtin) -> uniffi::Result<Self>
-    where
-        Self: Sized,
-    {
-        Ok(val.into())
-    }
-
-    fn from_custom(obj: Self) -> Self::Builtin {
-        obj.into()
-    }
-}
+uniffi::custom_type!(NodeId, u64, {
+    remote,
+    try_lift: |val| Ok(val.into()),
+    lower: |obj| obj.into(),
+});
@@ -1,14 +1,56 @@
 use std::sync::RwLock;
 
-use taffy::{AlignContent, AlignItems, AlignSelf, Display, FlexDirection, FlexWrap, GridAutoFlow, JustifyContent, Line, Overflow, Point, Position, Rect, Size};
+use serde::{Deserialize, Serialize};
+use taffy::{
+    AlignContent, AlignItems, AlignSelf, Display, FlexDirection, FlexWrap, GridAutoFlow,
+    JustifyContent, Line, Overflow, Point, Position, Rect, Size,
+};
 
-use crate::{Convert, Dimension, GridPlacement, LengthPercentage, LengthPercentageAuto, NonRepeatedTrackSizingFunction, TaffyResult, TrackSizingFunction};
+use crate::{
+    Convert, Dimension, GridPlacement, LengthPercentage, LengthPercentageAuto,
+    NonRepeatedTrackSizingFunction, StyleValidation, TaffyError, TaffyResult, TrackSizingFunction,
+};
 
-pub struct Style(pub(crate) RwLock<taffy::Style>);
+pub struct Style(pub(crate) RwLock<taffy::Style>, RwLock<StyleValidation>);
 
 impl Style {
     pub fn new() -> Self {
-        Self(RwLock::new(taffy::Style::DEFAULT))
+        taffy::Style::DEFAULT.into()
+    }
+
+    // A new style is strict, so its data has to be valid.
+    pub fn from_data(data: StyleData) -> TaffyResult<Self> {
+        Ok(StyleValidation::Strict.style(data.convert())?.into())
+    }
+
+    pub fn from_css(css: String) -> TaffyResult<Self> {
+        let style = Self::new();
+        style.apply_css(css)?;
+        Ok(style)
+    }
+
+    pub fn to_data(&self) -> TaffyResult<StyleData> {
+        Ok(self.0.read()?.convert())
+    }
+
+    pub fn to_css(&self) -> TaffyResult<String> {
+        crate::css::to_css(&*self.0.read()?)
+    }
+
+    pub fn apply_css(&self, css: String) -> TaffyResult<()> {
+        let validation = self.get_validation()?;
+        let mut style = self.0.write()?;
+        *style = validation.style(crate::css::apply_css(&style, &css)?)?;
+        Ok(())
+    }
+
+    pub fn get_validation(&self) -> TaffyResult<StyleValidation> {
+        Ok(*self.1.read()?)
+    }
+
+    pub fn set_validation(&self, value: StyleValidation) -> TaffyResult<()> {
+        *self.1.write()? = value;
+        Ok(())
     }
 
     pub fn get_display(&self) -> TaffyResult<Display> {
@@ -34,7 +76,7 @@ impl Style {
     }
 
     pub fn set_scrollbar_width(&self, value: f32) -> TaffyResult<()> {
-        self.0.write()?.scrollbar_width = value;
+        self.0.write()?.scrollbar_width = self.get_validation()?.scrollbar_width(value)?;
         Ok(())
     }
 
@@ -52,7 +94,7 @@ impl Style {
     }
 
     pub fn set_inset(&self, value: Rect<LengthPercentageAuto>) -> TaffyResult<()> {
-        self.0.write()?.inset = value.convert();
+        self.0.write()?.inset = self.get_validation()?.inset(value.convert())?;
         Ok(())
     }
 
@@ -61,7 +103,7 @@ impl Style {
     }
 
     pub fn set_size(&self, value: Size<Dimension>) -> TaffyResult<()> {
-        self.0.write()?.size = value.convert();
+        self.0.write()?.size = self.get_validation()?.size(value.convert())?;
         Ok(())
     }
 
@@ -70,7 +112,7 @@ impl Style {
     }
 
     pub fn set_min_size(&self, value: Size<Dimension>) -> TaffyResult<()> {
-        self.0.write()?.min_size = value.convert();
+        self.0.write()?.min_size = self.get_validation()?.min_size(value.convert())?;
         Ok(())
     }
 
@@ -79,7 +121,7 @@ impl Style {
     }
 
     pub fn set_max_size(&self, value: Size<Dimension>) -> TaffyResult<()> {
-        self.0.write()?.max_size = value.convert();
+        self.0.write()?.max_size = self.get_validation()?.max_size(value.convert())?;
         Ok(())
     }
 
@@ -88,7 +130,7 @@ impl Style {
     }
 
     pub fn set_aspect_ratio(&self, value: Option<f32>) -> TaffyResult<()> {
-        self.0.write()?.aspect_ratio = value;
+        self.0.write()?.aspect_ratio = self.get_validation()?.aspect_ratio(value)?;
         Ok(())
     }
 
@@ -97,7 +139,7 @@ impl Style {
     }
 
     pub fn set_margin(&self, value: Rect<LengthPercentageAuto>) -> TaffyResult<()> {
-        self.0.write()?.margin = value.convert();
+        self.0.write()?.margin = self.get_validation()?.margin(value.convert())?;
         Ok(())
     }
 
@@ -106,7 +148,7 @@ impl Style {
     }
 
     pub fn set_padding(&self, value: Rect<LengthPercentage>) -> TaffyResult<()> {
-        self.0.write()?.padding = value.convert();
+        self.0.write()?.padding = self.get_validation()?.padding(value.convert())?;
         Ok(())
     }
 
@@ -115,7 +157,7 @@ impl Style {
     }
 
     pub fn set_border(&self, value: Rect<LengthPercentage>) -> TaffyResult<()> {
-        self.0.write()?.border = value.convert();
+        self.0.write()?.border = self.get_validation()?.border(value.convert())?;
         Ok(())
     }
 
@@ -178,7 +220,7 @@ impl Style {
     }
 
     pub fn set_gap(&self, value: Size<LengthPercentage>) -> TaffyResult<()> {
-        self.0.write()?.gap = value.convert();
+        self.0.write()?.gap = self.get_validation()?.gap(value.convert())?;
         Ok(())
     }
 
@@ -205,7 +247,7 @@ impl Style {
     }
 
     pub fn set_flex_basis(&self, value: Dimension) -> TaffyResult<()> {
-        self.0.write()?.flex_basis = value.convert();
+        self.0.write()?.flex_basis = self.get_validation()?.flex_basis(value.convert())?;
         Ok(())
     }
 
@@ -214,7 +256,7 @@ impl Style {
     }
 
     pub fn set_flex_grow(&self, value: f32) -> TaffyResult<()> {
-        self.0.write()?.flex_grow = value;
+        self.0.write()?.flex_grow = self.get_validation()?.flex_grow(value)?;
         Ok(())
     }
 
@@ -223,7 +265,7 @@ impl Style {
     }
 
     pub fn set_flex_shrink(&self, value: f32) -> TaffyResult<()> {
-        self.0.write()?.flex_shrink = value;
+        self.0.write()?.flex_shrink = self.get_validation()?.flex_shrink(value)?;
         Ok(())
     }
 
@@ -232,7 +274,9 @@ impl Style {
     }
 
     pub fn set_grid_template_rows(&self, value: Vec<TrackSizingFunction>) -> TaffyResult<()> {
-        self.0.write()?.grid_template_rows = value.convert();
+        self.0.write()?.grid_template_rows = self
+            .get_validation()?
+            .tracks("grid-template-rows", value.convert())?;
         Ok(())
     }
 
@@ -241,7 +285,9 @@ impl Style {
     }
 
     pub fn set_grid_template_columns(&self, value: Vec<TrackSizingFunction>) -> TaffyResult<()> {
-        self.0.write()?.grid_template_columns = value.convert();
+        self.0.write()?.grid_template_columns = self
+            .get_validation()?
+            .tracks("grid-template-columns", value.convert())?;
         Ok(())
     }
 
@@ -253,7 +299,9 @@ impl Style {
         &self,
         value: Vec<NonRepeatedTrackSizingFunction>,
     ) -> TaffyResult<()> {
-        self.0.write()?.grid_auto_rows = value.convert();
+        self.0.write()?.grid_auto_rows = self
+            .get_validation()?
+            .track_sizes("grid-auto-rows", value.convert())?;
         Ok(())
     }
 
@@ -265,7 +313,9 @@ impl Style {
         &self,
         value: Vec<NonRepeatedTrackSizingFunction>,
     ) -> TaffyResult<()> {
-        self.0.write()?.grid_auto_columns = value.convert();
+        self.0.write()?.grid_auto_columns = self
+            .get_validation()?
+            .track_sizes("grid-auto-columns", value.convert())?;
         Ok(())
     }
 
@@ -297,8 +347,158 @@ impl Style {
     }
 }
 
+impl Default for Style {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl From<taffy::Style> for Style {
     fn from(value: taffy::Style) -> Self {
-        Self(RwLock::new(value.to_owned()))
+        Self(RwLock::new(value), RwLock::default())
+    }
+}
+
+impl TryFrom<&Style> for taffy::Style {
+    type Error = TaffyError;
+
+    fn try_from(value: &Style) -> TaffyResult<Self> {
+        Ok(value.0.read()?.clone())
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct StyleData {
+    pub display: Display,
+    pub overflow: Point<Overflow>,
+    pub scrollbar_width: f32,
+    pub position: Position,
+    pub inset: Rect<LengthPercentageAuto>,
+    pub size: Size<Dimension>,
+    pub min_size: Size<Dimension>,
+    pub max_size: Size<Dimension>,
+    pub aspect_ratio: Option<f32>,
+    pub margin: Rect<LengthPercentageAuto>,
+    pub padding: Rect<LengthPercentage>,
+    pub border: Rect<LengthPercentage>,
+    pub align_items: Option<AlignItems>,
+    pub align_self: Option<AlignSelf>,
+    pub justify_items: Option<AlignItems>,
+    pub justify_self: Option<AlignSelf>,
+    pub align_content: Option<AlignContent>,
+    pub justify_content: Option<JustifyContent>,
+    pub gap: Size<LengthPercentage>,
+    pub flex_direction: FlexDirection,
+    pub flex_wrap: FlexWrap,
+    pub flex_basis: Dimension,
+    pub flex_grow: f32,
+    pub flex_shrink: f32,
+    pub grid_template_rows: Vec<TrackSizingFunction>,
+    pub grid_template_columns: Vec<TrackSizingFunction>,
+    pub grid_auto_rows: Vec<NonRepeatedTrackSizingFunction>,
+    pub grid_auto_columns: Vec<NonRepeatedTrackSizingFunction>,
+    pub grid_auto_flow: GridAutoFlow,
+    #[serde(with = "grid_line")]
+    pub grid_row: Line<GridPlacement>,
+    #[serde(with = "grid_line")]
+    pub grid_column: Line<GridPlacement>,
+}
+
+// taffy only deserializes lines of its own placements, so these are written
+// as a start and end pair.
+mod grid_line {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+    use taffy::Line;
+
+    use crate::GridPlacement;
+
+    pub fn serialize<S: Serializer>(
+        line: &Line<GridPlacement>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        (&line.start, &line.end).serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Line<GridPlacement>, D::Error> {
+        let (start, end) = Deserialize::deserialize(deserializer)?;
+        Ok(Line { start, end })
+    }
+}
+
+impl Convert<StyleData> for taffy::Style {
+    fn convert(&self) -> StyleData {
+        StyleData {
+            display: self.display,
+            overflow: self.overflow,
+            scrollbar_width: self.scrollbar_width,
+            position: self.position,
+            inset: self.inset.convert(),
+            size: self.size.convert(),
+            min_size: self.min_size.convert(),
+            max_size: self.max_size.convert(),
+            aspect_ratio: self.aspect_ratio,
+            margin: self.margin.convert(),
+            padding: self.padding.convert(),
+            border: self.border.convert(),
+            align_items: self.align_items,
+            align_self: self.align_self,
+            justify_items: self.justify_items,
+            justify_self: self.justify_self,
+            align_content: self.align_content,
+            justify_content: self.justify_content,
+            gap: self.gap.convert(),
+            flex_direction: self.flex_direction,
+            flex_wrap: self.flex_wrap,
+            flex_basis: self.flex_basis.convert(),
+            flex_grow: self.flex_grow,
+            flex_shrink: self.flex_shrink,
+            grid_template_rows: self.grid_template_rows.convert(),
+            grid_template_columns: self.grid_template_columns.convert(),
+            grid_auto_rows: self.grid_auto_rows.convert(),
+            grid_auto_columns: self.grid_auto_columns.convert(),
+            grid_auto_flow: self.grid_auto_flow,
+            grid_row: self.grid_row.convert(),
+            grid_column: self.grid_column.convert(),
+        }
+    }
+}
+
+impl Convert<taffy::Style> for StyleData {
+    fn convert(&self) -> taffy::Style {
+        taffy::Style {
+            display: self.display,
+            overflow: self.overflow,
+            scrollbar_width: self.scrollbar_width,
+            position: self.position,
+            inset: self.inset.convert(),
+            size: self.size.convert(),
+            min_size: self.min_size.convert(),
+            max_size: self.max_size.convert(),
+            aspect_ratio: self.aspect_ratio,
+            margin: self.margin.convert(),
+            padding: self.padding.convert(),
+            border: self.border.convert(),
+            align_items: self.align_items,
+            align_self: self.align_self,
+            justify_items: self.justify_items,
+            justify_self: self.justify_self,
+            align_content: self.align_content,
+            justify_content: self.justify_content,
+            gap: self.gap.convert(),
+            flex_direction: self.flex_direction,
+            flex_wrap: self.flex_wrap,
+            flex_basis: self.flex_basis.convert(),
+            flex_grow: self.flex_grow,
+            flex_shrink: self.flex_shrink,
+            grid_template_rows: self.grid_template_rows.convert(),
+            grid_template_columns: self.grid_template_columns.convert(),
+            grid_auto_rows: self.grid_auto_rows.convert(),
+            grid_auto_columns: self.grid_auto_columns.convert(),
+            grid_auto_flow: self.grid_auto_flow,
+            grid_row: self.grid_row.convert(),
+            grid_column: self.grid_column.convert(),
+        }
     }
 }
@@ -0,0 +1,277 @@
+use std::sync::{Arc, RwLock, Weak};
+
+use rustybuzz::{Face, UnicodeBuffer};
+use taffy::{NodeId, Size};
+use unicode_linebreak::{linebreaks, BreakOpportunity};
+
+use crate::{
+    AvailableSpace, MeasureError, MeasureFunction, NodeContext, TaffyError, TaffyResult, TaffyTree,
+};
+
+#[derive(Clone, Copy)]
+pub enum TextWrap {
+    Word,
+    NoWrap,
+}
+
+#[derive(Clone)]
+pub struct TextStyle {
+    pub font: u32,
+    pub font_size: f32,
+    pub line_height: f32,
+    pub wrap: TextWrap,
+}
+
+pub struct FontCollection(RwLock<Vec<Vec<u8>>>);
+
+impl FontCollection {
+    pub fn new() -> Self {
+        Self(RwLock::new(Vec::new()))
+    }
+
+    pub fn load_font(&self, data: Vec<u8>) -> TaffyResult<u32> {
+        Face::from_slice(&data, 0).ok_or(TaffyError::InvalidFontData)?;
+        let mut fonts = self.0.write()?;
+        fonts.push(data);
+        Ok(fonts.len() as u32 - 1)
+    }
+
+    pub fn get_font_count(&self) -> TaffyResult<u32> {
+        Ok(self.0.read()?.len() as u32)
+    }
+
+    fn check_font(&self, font: u32) -> TaffyResult<()> {
+        if font < self.get_font_count()? {
+            Ok(())
+        } else {
+            Err(TaffyError::InvalidFont { font })
+        }
+    }
+}
+
+impl Default for FontCollection {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+pub struct TextNode {
+    fonts: Arc<FontCollection>,
+    state: RwLock<TextNodeState>,
+    // The node the text is attached to, which has to be laid out again when
+    // the text or the style changes. The tree owns the text node through its
+    // measure function, so it's only referenced weakly here.
+    attached: RwLock<Option<(Weak<TaffyTree>, NodeId)>>,
+}
+
+struct TextNodeState {
+    text: String,
+    style: TextStyle,
+    // Shaping is by far the most expensive part of measuring, so its result is
+    // kept until the text or the style changes.
+    segments: Option<Vec<Segment>>,
+}
+
+// The text between two line break opportunities.
+struct Segment {
+    width: f32,
+    trailing_whitespace: f32,
+    mandatory_break: bool,
+}
+
+impl TextNode {
+    pub fn new(fonts: Arc<FontCollection>, text: String, style: TextStyle) -> TaffyResult<Self> {
+        fonts.check_font(style.font)?;
+        Ok(Self {
+            fonts,
+            state: RwLock::new(TextNodeState {
+                text,
+                style,
+                segments: None,
+            }),
+            attached: RwLock::new(None),
+        })
+    }
+
+    pub fn get_text(&self) -> TaffyResult<String> {
+        Ok(self.state.read()?.text.clone())
+    }
+
+    pub fn set_text(&self, value: String) -> TaffyResult<()> {
+        let mut state = self.state.write()?;
+        state.text = value;
+        state.segments = None;
+        drop(state);
+        self.mark_dirty()
+    }
+
+    pub fn get_style(&self) -> TaffyResult<TextStyle> {
+        Ok(self.state.read()?.style.clone())
+    }
+
+    pub fn set_style(&self, value: TextStyle) -> TaffyResult<()> {
+        self.fonts.check_font(value.font)?;
+        let mut state = self.state.write()?;
+        state.style = value;
+        state.segments = None;
+        drop(state);
+        self.mark_dirty()
+    }
+
+    pub fn measure(
+        &self,
+        known_size: Size<Option<f32>>,
+        available_space: Size<AvailableSpace>,
+    ) -> TaffyResult<Size<f32>> {
+        if let Size {
+            width: Some(width),
+            height: Some(height),
+        } = known_size
+        {
+            return Ok(Size { width, height });
+        }
+
+        let mut state = self.state.write()?;
+        if state.segments.is_none() {
+            state.segments = Some(self.shape(&state.text, &state.style)?);
+        }
+        let state = &*state;
+        let segments = state.segments.as_deref().unwrap_or_default();
+        let wrap = matches!(state.style.wrap, TextWrap::Word);
+
+        let max_content_width = break_lines(segments, f32::INFINITY, wrap).0;
+        let min_content_width = if wrap {
+            segments
+                .iter()
+                .map(|segment| segment.width)
+                .fold(0.0, f32::max)
+        } else {
+            max_content_width
+        };
+
+        let width_constraint = known_size.width.unwrap_or(match available_space.width {
+            AvailableSpace::MinContent => min_content_width,
+            AvailableSpace::MaxContent => max_content_width,
+            AvailableSpace::Definite { value } => {
+                value.max(min_content_width).min(max_content_width)
+            }
+        });
+        let (width, line_count) = break_lines(segments, width_constraint, wrap);
+
+        Ok(Size {
+            width: known_size.width.unwrap_or(width),
+            height: known_size
+                .height
+                .unwrap_or(line_count as f32 * state.style.font_size * state.style.line_height),
+        })
+    }
+
+    pub fn attach(self: Arc<Self>, tree: Arc<TaffyTree>, node: NodeId) -> TaffyResult<()> {
+        tree.set_measure_function(node, Box::new(TextMeasureFunction(self.clone())))?;
+        *self.attached.write()? = Some((Arc::downgrade(&tree), node));
+        Ok(())
+    }
+
+    // Called without holding the state lock, which a layout pass on the tree
+    // takes while measuring.
+    fn mark_dirty(&self) -> TaffyResult<()> {
+        let attached = self.attached.read()?.clone();
+        match attached.and_then(|(tree, node)| Some((tree.upgrade()?, node))) {
+            Some((tree, node)) => tree.mark_dirty(node),
+            None => Ok(()),
+        }
+    }
+
+    fn shape(&self, text: &str, style: &TextStyle) -> TaffyResult<Vec<Segment>> {
+        let fonts = self.fonts.0.read()?;
+        let data = fonts
+            .get(style.font as usize)
+            .ok_or(TaffyError::InvalidFont { font: style.font })?;
+        let face = Face::from_slice(data, 0).ok_or(TaffyError::InvalidFontData)?;
+        let scale = style.font_size / face.units_per_em() as f32;
+
+        let mut buffer = UnicodeBuffer::new();
+        buffer.push_str(text);
+        let glyphs = rustybuzz::shape(&face, &[], buffer);
+
+        let breaks = linebreaks(text).collect::<Vec<_>>();
+        let mut segments = breaks
+            .iter()
+            .map(|(_, opportunity)| Segment {
+                width: 0.0,
+                trailing_whitespace: 0.0,
+                mandatory_break: *opportunity == BreakOpportunity::Mandatory,
+            })
+            .collect::<Vec<_>>();
+
+        for (info, position) in glyphs.glyph_infos().iter().zip(glyphs.glyph_positions()) {
+            let cluster = info.cluster as usize;
+            let index = breaks.partition_point(|(offset, _)| *offset <= cluster);
+            let Some(segment) = segments.get_mut(index) else {
+                continue;
+            };
+            let advance = position.x_advance as f32 * scale;
+            if text[cluster..].starts_with(char::is_whitespace) {
+                segment.trailing_whitespace += advance;
+            } else {
+                segment.width += segment.trailing_whitespace + advance;
+                segment.trailing_whitespace = 0.0;
+            }
+        }
+
+        if text.is_empty() {
+            segments.clear();
+        }
+
+        Ok(segments)
+    }
+}
+
+// Greedily fills lines up to `max_width` and returns the width of the widest
+// line together with the number of lines.
+fn break_lines(segments: &[Segment], max_width: f32, wrap: bool) -> (f32, usize) {
+    let mut widest_line = 0.0f32;
+    let mut line_count = 0;
+    let mut line_width = None::<f32>;
+    let mut visible_width = 0.0f32;
+
+    for segment in segments {
+        if let Some(width) = line_width {
+            if wrap && width + segment.width > max_width {
+                widest_line = widest_line.max(visible_width);
+                line_count += 1;
+                line_width = None;
+            }
+        }
+
+        let start = line_width.unwrap_or(0.0);
+        visible_width = start + segment.width;
+        line_width = Some(visible_width + segment.trailing_whitespace);
+
+        if segment.mandatory_break {
+            widest_line = widest_line.max(visible_width);
+            line_count += 1;
+            line_width = None;
+        }
+    }
+
+    (widest_line, line_count)
+}
+
+struct TextMeasureFunction(Arc<TextNode>);
+
+impl MeasureFunction for TextMeasureFunction {
+    fn measure(
+        &self,
+        known_size: Size<Option<f32>>,
+        available_space: Size<AvailableSpace>,
+        _node: NodeId,
+        _context: Option<NodeContext>,
+    ) -> Result<Size<f32>, MeasureError> {
+        self.0
+            .measure(known_size, available_space)
+            .map_err(|error| MeasureError::Failed {
+                reason: error.to_string(),
+            })
+    }
+}
@@ -1,35 +1,306 @@
-use std::sync::{Arc, RwLock};
+use std::{
+    cell::RefCell,
+    collections::{HashMap, HashSet},
+    fmt::Write,
+    ops::Deref,
+    panic::{self, AssertUnwindSafe},
+    sync::{Arc, RwLock, RwLockReadGuard, RwLockWriteGuard},
+};
 
-use taffy::{Layout, NodeId, Size};
+use taffy::{Display, Layout, NodeId, Overflow, Point, PrintTree, Rect, Size, TraversePartialTree};
 
-use crate::{AvailableSpace, Convert, Style, TaffyResult};
+use crate::{
+    document::{LayoutDocument, NodeDocument, TreeDocument},
+    error::panic_message,
+    html::FixtureText,
+    AvailableSpace, Convert, MeasureError, NodeContext, Style, StyleData, StyleValidation,
+    TaffyError, TaffyResult,
+};
 
-pub struct TaffyTree(RwLock<taffy::TaffyTree>);
+pub struct TaffyTree(RwLock<Tree>);
+
+// taffy indexes its storage with node ids directly, so an id that was removed
+// or belongs to another tree panics, and it leaves a node listed under its old
+// parent when it's attached elsewhere. The nodes that exist are tracked
+// alongside it so that ids can be checked before they reach taffy. Mutations
+// go through `taffy` explicitly to keep that set up to date.
+struct Tree {
+    taffy: taffy::TaffyTree<NodeData>,
+    nodes: HashSet<NodeId>,
+    // Scrolling doesn't affect layout, so the offsets are kept out of taffy,
+    // which marks a node dirty whenever data is attached to it.
+    scroll_offsets: HashMap<NodeId, Point<f32>>,
+    // Applies to every style the tree takes, whatever the style's own mode.
+    style_validation: StyleValidation,
+}
+
+impl Deref for Tree {
+    type Target = taffy::TaffyTree<NodeData>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.taffy
+    }
+}
+
+impl Tree {
+    fn new(taffy: taffy::TaffyTree<NodeData>, capacity: usize) -> Self {
+        Self {
+            taffy,
+            nodes: HashSet::with_capacity(capacity),
+            scroll_offsets: HashMap::new(),
+            style_validation: StyleValidation::default(),
+        }
+    }
+
+    fn add(&mut self, node: NodeId) -> NodeId {
+        self.nodes.insert(node);
+        node
+    }
+
+    fn check_node(&self, node_id: NodeId) -> TaffyResult<()> {
+        self.check(node_id, |node_id| TaffyError::InvalidInputNode { node_id })
+    }
+
+    fn check_parent(&self, node_id: NodeId) -> TaffyResult<()> {
+        self.check(node_id, |node_id| TaffyError::InvalidParentNode { node_id })
+    }
+
+    fn check(&self, node_id: NodeId, error: fn(NodeId) -> TaffyError) -> TaffyResult<()> {
+        if self.nodes.contains(&node_id) {
+            Ok(())
+        } else {
+            Err(error(node_id))
+        }
+    }
+
+    // Checks that `children` exist, are listed once, and that none of them is
+    // `parent` or one of its ancestors, as taffy recurses through a cycle
+    // until the stack overflows.
+    fn check_children(&self, parent: Option<NodeId>, children: &[NodeId]) -> TaffyResult<()> {
+        let mut ancestors = HashSet::new();
+        let mut current = parent;
+        while let Some(node) = current {
+            ancestors.insert(node);
+            current = self.taffy.parent(node);
+        }
+        let mut seen = HashSet::with_capacity(children.len());
+        for &child in children {
+            self.check(child, |node_id| TaffyError::InvalidChildNode { node_id })?;
+            if !seen.insert(child) {
+                return Err(TaffyError::DuplicateChild { node_id: child });
+            }
+            if let Some(parent) = parent.filter(|_| ancestors.contains(&child)) {
+                return Err(TaffyError::CyclicChild { parent, child });
+            }
+        }
+        Ok(())
+    }
+
+    // Marks every node dirty. Marking a node dirty also marks its ancestors,
+    // so only the leaves need to be visited.
+    fn invalidate_layouts(&mut self) -> TaffyResult<()> {
+        for &node in &self.nodes {
+            if self.taffy.child_count(node) == 0 {
+                self.taffy.mark_dirty(node)?;
+            }
+        }
+        Ok(())
+    }
+
+    // Checks that every node is listed by its parent and only by its parent,
+    // and that following children from the roots reaches every node, which
+    // leaves no room for a cycle.
+    fn validate(&self) -> TaffyResult<()> {
+        let inconsistent = |message: String| TaffyError::Internal {
+            reason: format!("tree is inconsistent: {message}"),
+        };
+        if self.taffy.total_node_count() != self.nodes.len() {
+            return Err(inconsistent(format!(
+                "taffy holds {} nodes instead of {}",
+                self.taffy.total_node_count(),
+                self.nodes.len()
+            )));
+        }
+        let mut stack = Vec::new();
+        for &node in &self.nodes {
+            match self.taffy.parent(node) {
+                Some(parent) if !self.nodes.contains(&parent) => {
+                    return Err(inconsistent(format!(
+                        "{node:?} has removed parent {parent:?}"
+                    )));
+                }
+                Some(_) => {}
+                None => stack.push(node),
+            }
+            for child in self.taffy.child_ids(node) {
+                if !self.nodes.contains(&child) || self.taffy.parent(child) != Some(node) {
+                    return Err(inconsistent(format!(
+                        "{node:?} lists {child:?}, which isn't its child"
+                    )));
+                }
+            }
+        }
+        let mut reached = 0;
+        while let Some(node) = stack.pop() {
+            reached += 1;
+            stack.extend(self.taffy.child_ids(node));
+        }
+        if reached != self.nodes.len() {
+            return Err(inconsistent(format!(
+                "{} nodes are in a cycle",
+                self.nodes.len() - reached
+            )));
+        }
+        Ok(())
+    }
+
+    // Removes `child` from the children of its current parent, if it has one.
+    fn detach(&mut self, child: NodeId) -> TaffyResult<()> {
+        if let Some(parent) = self.taffy.parent(child) {
+            self.taffy.remove_child(parent, child)?;
+        }
+        Ok(())
+    }
+}
+
+thread_local! {
+    // Addresses of the trees computing a layout on this thread. A measure
+    // function calling back into one of them would deadlock on its lock.
+    static COMPUTING: RefCell<Vec<usize>> = const { RefCell::new(Vec::new()) };
+}
+
+struct ComputingGuard(usize);
+
+impl Drop for ComputingGuard {
+    fn drop(&mut self) {
+        COMPUTING.with(|computing| computing.borrow_mut().retain(|tree| *tree != self.0));
+    }
+}
+
+#[derive(Default)]
+struct NodeData {
+    context: Option<NodeContext>,
+    measure_function: Option<Box<dyn MeasureFunction>>,
+}
+
+impl NodeData {
+    fn with_context(context: NodeContext) -> Self {
+        Self {
+            context: Some(context),
+            ..Default::default()
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.context.is_none() && self.measure_function.is_none()
+    }
+
+    fn measure(
+        &self,
+        known_size: Size<Option<f32>>,
+        avaliable_space: Size<taffy::AvailableSpace>,
+        node: NodeId,
+    ) -> Option<Result<Size<f32>, MeasureError>> {
+        self.measure_function.as_ref().map(|measure_function| {
+            measure_function.measure(
+                known_size,
+                avaliable_space.convert(),
+                node,
+                self.context.clone(),
+            )
+        })
+    }
+}
+
+// Taffy can't abort a layout pass, so after the first failing measure function
+// every further leaf is skipped and sized as zero. A measure function that
+// panics fails like any other. All of those placeholder sizes are evicted from
+// the cache again before the error is reported.
+#[derive(Default)]
+struct MeasureFailure {
+    error: Option<(NodeId, MeasureError)>,
+    skipped: Vec<NodeId>,
+}
+
+impl MeasureFailure {
+    fn measure(
+        &mut self,
+        node: NodeId,
+        measure: impl FnOnce() -> Result<Size<f32>, MeasureError>,
+    ) -> Size<f32> {
+        if self.error.is_some() {
+            self.skipped.push(node);
+            return Size::ZERO;
+        }
+        panic::catch_unwind(AssertUnwindSafe(measure))
+            .unwrap_or_else(|payload| {
+                Err(MeasureError::Failed {
+                    reason: format!("panicked: {}", panic_message(&*payload)),
+                })
+            })
+            .unwrap_or_else(|error| {
+                self.error = Some((node, error));
+                Size::ZERO
+            })
+    }
+
+    fn finish(self, tree: &mut taffy::TaffyTree<NodeData>) -> TaffyResult<()> {
+        let Some((node_id, error)) = self.error else {
+            return Ok(());
+        };
+        tree.mark_dirty(node_id)?;
+        for node in self.skipped {
+            tree.mark_dirty(node)?;
+        }
+        Err(TaffyError::MeasureFailed {
+            node_id,
+            reason: error.to_string(),
+        })
+    }
+}
 
 impl TaffyTree {
     pub fn new() -> Self {
-        Self(RwLock::new(taffy::TaffyTree::new()))
+        Self(RwLock::new(Tree::new(taffy::TaffyTree::new(), 0)))
     }
 
     pub fn with_capacity(capacity: u64) -> Self {
-        Self(RwLock::new(taffy::TaffyTree::with_capacity(
+        Self(RwLock::new(Tree::new(
+            taffy::TaffyTree::with_capacity(capacity as usize),
             capacity as usize,
         )))
     }
 
     pub fn get_child_at_index(&self, parent: NodeId, child_index: u64) -> TaffyResult<NodeId> {
-        Ok(self
-            .0
-            .read()?
-            .child_at_index(parent, child_index as usize)?)
+        let tree = self.read()?;
+        tree.check_parent(parent)?;
+        Ok(tree.child_at_index(parent, child_index as usize)?)
     }
 
     pub fn get_children(&self, parent: NodeId) -> TaffyResult<std::vec::Vec<NodeId>> {
-        Ok(self.0.read()?.children(parent)?)
+        let tree = self.read()?;
+        tree.check_parent(parent)?;
+        Ok(tree.children(parent)?)
     }
 
     pub fn clear(&self) -> TaffyResult<()> {
-        Ok(self.0.write()?.clear())
+        let mut tree = self.write()?;
+        tree.taffy.clear();
+        tree.nodes.clear();
+        tree.scroll_offsets.clear();
+        Ok(())
+    }
+
+    // Makes the tree usable again after a panic poisoned its lock. Panics
+    // during a layout pass are contained by compute(), but any other call
+    // that panics while changing the tree leaves it poisoned. The tree is
+    // checked for consistency, and every layout is recomputed on the next
+    // pass as the panic may have interrupted one.
+    pub fn recover(&self) -> TaffyResult<()> {
+        self.0.clear_poison();
+        let mut tree = self.write()?;
+        tree.validate()?;
+        tree.invalidate_layouts()
     }
 
     pub fn compute_layout(
@@ -37,10 +308,20 @@ impl TaffyTree {
         node: NodeId,
         available_space: Size<AvailableSpace>,
     ) -> TaffyResult<()> {
-        Ok(self
-            .0
-            .write()?
-            .compute_layout(node, available_space.convert())?)
+        self.compute(node, |tree| {
+            let mut failure = MeasureFailure::default();
+            tree.compute_layout_with_measure(
+                node,
+                available_space.convert(),
+                |known_size, avaliable_space, node, data| {
+                    failure.measure(node, || {
+                        data.and_then(|data| data.measure(known_size, avaliable_space, node))
+                            .unwrap_or(Ok(Size::ZERO))
+                    })
+                },
+            )?;
+            failure.finish(tree)
+        })
     }
 
     pub fn compute_layout_with_measure(
@@ -49,27 +330,61 @@ impl TaffyTree {
         available_space: Size<AvailableSpace>,
         measure_function: Box<dyn MeasureFunction>,
     ) -> TaffyResult<()> {
-        Ok(self.0.write()?.compute_layout_with_measure(
-            node_id,
-            available_space.convert(),
-            |known_size, avaliable_space, node, _| measure_function.measure(known_size, avaliable_space.convert(), node),
-        )?)
+        self.compute(node_id, |tree| {
+            let mut failure = MeasureFailure::default();
+            tree.compute_layout_with_measure(
+                node_id,
+                available_space.convert(),
+                |known_size, avaliable_space, node, data| {
+                    let data = data.map(|data| &*data);
+                    failure.measure(node, || {
+                        data.and_then(|data| data.measure(known_size, avaliable_space, node))
+                            .unwrap_or_else(|| {
+                                measure_function.measure(
+                                    known_size,
+                                    avaliable_space.convert(),
+                                    node,
+                                    data.and_then(|data| data.context.clone()),
+                                )
+                            })
+                    })
+                },
+            )?;
+            failure.finish(tree)
+        })
     }
 
     pub fn is_dirty(&self, node: NodeId) -> TaffyResult<bool> {
-        Ok(self.0.write()?.dirty(node)?)
+        let tree = self.read()?;
+        tree.check_node(node)?;
+        Ok(tree.dirty(node)?)
     }
 
     pub fn disable_rounding(&self) -> TaffyResult<()> {
-        Ok(self.0.write()?.disable_rounding())
+        self.write()?.taffy.disable_rounding();
+        Ok(())
     }
 
     pub fn enable_rounding(&self) -> TaffyResult<()> {
-        Ok(self.0.write()?.enable_rounding())
+        self.write()?.taffy.enable_rounding();
+        Ok(())
     }
 
-    pub fn get_node_context(&self, node: NodeId) -> TaffyResult<()> {
-        Ok(self.0.read()?.get_node_context(node).cloned().unwrap_or(()))
+    pub fn get_style_validation(&self) -> TaffyResult<StyleValidation> {
+        Ok(self.read()?.style_validation)
+    }
+
+    pub fn set_style_validation(&self, mode: StyleValidation) -> TaffyResult<()> {
+        self.write()?.style_validation = mode;
+        Ok(())
+    }
+
+    pub fn get_node_context(&self, node: NodeId) -> TaffyResult<Option<NodeContext>> {
+        let tree = self.read()?;
+        tree.check_node(node)?;
+        Ok(tree
+            .get_node_context(node)
+            .and_then(|data| data.context.clone()))
     }
 
     pub fn insert_child_at_index(
@@ -78,29 +393,141 @@ impl TaffyTree {
         child_index: u64,
         child: NodeId,
     ) -> TaffyResult<()> {
-        Ok(self
-            .0
-            .write()?
+        let mut tree = self.write()?;
+        tree.check_parent(parent)?;
+        tree.check_children(Some(parent), &[child])?;
+        // Moving a child within its parent leaves one fewer sibling to insert
+        // it among.
+        let mut child_count = tree.child_count(parent) as u64;
+        if tree.parent(child) == Some(parent) {
+            child_count -= 1;
+        }
+        if child_index > child_count {
+            return Err(TaffyError::ChildIndexOutOfBounds {
+                parent,
+                child_index,
+                child_count,
+            });
+        }
+        tree.detach(child)?;
+        Ok(tree
+            .taffy
             .insert_child_at_index(parent, child_index as usize, child)?)
     }
 
     pub fn get_layout(&self, node: NodeId) -> TaffyResult<Layout> {
-        Ok(self.0.read()?.layout(node)?.to_owned())
+        let tree = self.read()?;
+        tree.check_node(node)?;
+        Ok(tree.layout(node)?.to_owned())
+    }
+
+    pub fn get_layouts(&self, root: NodeId) -> TaffyResult<Vec<NodeLayout>> {
+        let tree = self.read()?;
+        tree.check_node(root)?;
+        let mut layouts = Vec::new();
+        let mut stack = vec![root];
+        while let Some(node) = stack.pop() {
+            layouts.push(NodeLayout {
+                node,
+                layout: *tree.layout(node)?,
+            });
+            stack.extend(tree.children(node)?.into_iter().rev());
+        }
+        Ok(layouts)
+    }
+
+    pub fn get_absolute_layout(&self, node: NodeId) -> TaffyResult<Layout> {
+        let tree = self.read()?;
+        tree.check_node(node)?;
+        let mut layout = *tree.layout(node)?;
+        layout.location = absolute_location(&tree, node)?;
+        Ok(layout)
+    }
+
+    pub fn get_absolute_layouts(&self, root: NodeId) -> TaffyResult<Vec<NodeLayout>> {
+        let tree = self.read()?;
+        tree.check_node(root)?;
+        let mut layouts = Vec::new();
+        let mut stack = vec![(root, absolute_location(&tree, root)?)];
+        while let Some((node, location)) = stack.pop() {
+            let mut layout = *tree.layout(node)?;
+            layout.location = location;
+            layouts.push(NodeLayout { node, layout });
+
+            let origin = content_origin(&tree, node, location);
+            for child in tree.children(node)?.into_iter().rev() {
+                let child_location = tree.layout(child)?.location;
+                stack.push((
+                    child,
+                    Point {
+                        x: origin.x + child_location.x,
+                        y: origin.y + child_location.y,
+                    },
+                ));
+            }
+        }
+        Ok(layouts)
+    }
+
+    pub fn hit_test(&self, root: NodeId, point: Point<f32>) -> TaffyResult<Vec<NodeId>> {
+        self.query_nodes(root, |rect| {
+            rect.left <= point.x
+                && point.x < rect.right
+                && rect.top <= point.y
+                && point.y < rect.bottom
+        })
+    }
+
+    pub fn hit_test_rect(&self, root: NodeId, area: Rect<f32>) -> TaffyResult<Vec<NodeId>> {
+        self.query_nodes(root, |rect| {
+            rect.left < area.right
+                && area.left < rect.right
+                && rect.top < area.bottom
+                && area.top < rect.bottom
+        })
+    }
+
+    pub fn set_scroll_offset(&self, node: NodeId, offset: Point<f32>) -> TaffyResult<()> {
+        let mut tree = self.write()?;
+        tree.check_node(node)?;
+        if offset == Point::ZERO {
+            tree.scroll_offsets.remove(&node);
+        } else {
+            tree.scroll_offsets.insert(node, offset);
+        }
+        Ok(())
+    }
+
+    pub fn get_scroll_offset(&self, node: NodeId) -> TaffyResult<Point<f32>> {
+        let tree = self.read()?;
+        tree.check_node(node)?;
+        Ok(scroll_offset(&tree, node))
     }
 
     pub fn mark_dirty(&self, node: NodeId) -> TaffyResult<()> {
-        Ok(self.0.write()?.mark_dirty(node)?)
+        let mut tree = self.write()?;
+        tree.check_node(node)?;
+        Ok(tree.taffy.mark_dirty(node)?)
     }
 
     pub fn new_leaf(&self, layout: std::sync::Arc<Style>) -> TaffyResult<NodeId> {
-        Ok(self.0.write()?.new_leaf(layout.0.read()?.clone())?)
+        let mut tree = self.write()?;
+        let style = tree.style_validation.style(layout.0.read()?.clone())?;
+        let node = tree.taffy.new_leaf(style)?;
+        Ok(tree.add(node))
     }
 
-    pub fn new_leaf_with_context(&self, layout: std::sync::Arc<Style>) -> TaffyResult<NodeId> {
-        Ok(self
-            .0
-            .write()?
-            .new_leaf_with_context(layout.0.read()?.clone(), ())?)
+    pub fn new_leaf_with_context(
+        &self,
+        layout: std::sync::Arc<Style>,
+        context: NodeContext,
+    ) -> TaffyResult<NodeId> {
+        let mut tree = self.write()?;
+        let style = tree.style_validation.style(layout.0.read()?.clone())?;
+        let node = tree
+            .taffy
+            .new_leaf_with_context(style, NodeData::with_context(context))?;
+        Ok(tree.add(node))
     }
 
     pub fn new_with_children(
@@ -108,32 +535,116 @@ impl TaffyTree {
         layout: std::sync::Arc<Style>,
         children: std::vec::Vec<NodeId>,
     ) -> TaffyResult<NodeId> {
-        Ok(self
-            .0
-            .write()?
-            .new_with_children(layout.0.read()?.clone(), &children)?)
+        let mut tree = self.write()?;
+        let style = tree.style_validation.style(layout.0.read()?.clone())?;
+        tree.check_children(None, &children)?;
+        for &child in &children {
+            tree.detach(child)?;
+        }
+        let node = tree.taffy.new_with_children(style, &children)?;
+        Ok(tree.add(node))
     }
 
     pub fn get_parent(&self, child_id: NodeId) -> TaffyResult<std::option::Option<NodeId>> {
-        Ok(self.0.read()?.parent(child_id))
+        let tree = self.read()?;
+        tree.check_node(child_id)?;
+        Ok(tree.parent(child_id))
     }
 
     pub fn print_tree(&self, root: NodeId) -> TaffyResult<()> {
-        Ok(self.0.write()?.print_tree(root))
+        let mut tree = self.write()?;
+        tree.check_node(root)?;
+        tree.taffy.print_tree(root);
+        Ok(())
+    }
+
+    pub fn debug_tree(&self, root: NodeId) -> TaffyResult<String> {
+        let tree = self.read()?;
+        tree.check_node(root)?;
+        let mut output = String::from("TREE\n");
+        write_debug_tree(&tree, root, &mut output)?;
+        Ok(output)
+    }
+
+    pub fn debug_nodes(&self, root: NodeId) -> TaffyResult<Vec<DebugNode>> {
+        let tree = self.read()?;
+        tree.check_node(root)?;
+        let mut nodes = Vec::new();
+        let mut stack = vec![(root, 0)];
+        while let Some((node, depth)) = stack.pop() {
+            nodes.push(DebugNode {
+                node,
+                depth,
+                label: tree.get_debug_label(node).to_owned(),
+                style: tree.style(node)?.convert(),
+                layout: *tree.layout(node)?,
+            });
+            stack.extend(
+                tree.children(node)?
+                    .into_iter()
+                    .rev()
+                    .map(|child| (child, depth + 1)),
+            );
+        }
+        Ok(nodes)
+    }
+
+    pub fn to_json(&self, root: NodeId, include_layouts: bool) -> TaffyResult<String> {
+        self.export_document(root, include_layouts)?.to_json()
+    }
+
+    pub fn from_json(&self, json: String) -> TaffyResult<NodeId> {
+        self.import_document(TreeDocument::from_json(&json)?)
+    }
+
+    pub fn export_binary(&self, root: NodeId) -> TaffyResult<Vec<u8>> {
+        self.export_document(root, false)?.to_binary()
+    }
+
+    pub fn import_binary(&self, data: Vec<u8>) -> TaffyResult<NodeId> {
+        self.import_document(TreeDocument::from_binary(&data)?)
+    }
+
+    pub fn from_html_fixture(&self, html: String) -> TaffyResult<NodeId> {
+        let fixture = crate::html::parse_fixture(&html)?;
+        let mut tree = self.write()?;
+        let ids = build_document(&mut tree, fixture.document)?;
+        for (index, text) in fixture.texts {
+            let data = NodeData {
+                measure_function: Some(Box::new(FixtureText(text))),
+                ..Default::default()
+            };
+            tree.taffy.set_node_context(ids[index], Some(data))?;
+        }
+        Ok(ids[0])
     }
 
     pub fn remove(&self, node: NodeId) -> TaffyResult<NodeId> {
-        Ok(self.0.write()?.remove(node)?)
+        let mut tree = self.write()?;
+        tree.check_node(node)?;
+        // taffy's remove neither drops the node's data nor marks its parent
+        // dirty, and clearing the data does both.
+        tree.taffy.set_node_context(node, None)?;
+        tree.nodes.remove(&node);
+        tree.scroll_offsets.remove(&node);
+        Ok(tree.taffy.remove(node)?)
     }
 
     pub fn remove_child(&self, parent: NodeId, child: NodeId) -> TaffyResult<NodeId> {
-        Ok(self.0.write()?.remove_child(parent, child)?)
+        let mut tree = self.write()?;
+        tree.check_parent(parent)?;
+        tree.check(child, |node_id| TaffyError::InvalidChildNode { node_id })?;
+        if tree.parent(child) != Some(parent) {
+            return Err(TaffyError::NotAChild { parent, child });
+        }
+        Ok(tree.taffy.remove_child(parent, child)?)
     }
 
     pub fn remove_child_at_index(&self, parent: NodeId, child_index: u64) -> TaffyResult<NodeId> {
-        Ok(self
-            .0
-            .write()?
+        let mut tree = self.write()?;
+        tree.check_parent(parent)?;
+        Ok(tree
+            .taffy
             .remove_child_at_index(parent, child_index as usize)?)
     }
 
@@ -143,38 +654,412 @@ impl TaffyTree {
         child_index: u64,
         new_child: NodeId,
     ) -> TaffyResult<NodeId> {
-        Ok(self
-            .0
-            .write()?
-            .replace_child_at_index(parent, child_index as usize, new_child)?)
+        let mut tree = self.write()?;
+        tree.check_parent(parent)?;
+        tree.check_children(Some(parent), &[new_child])?;
+        let mut child_index = child_index as usize;
+        let children = tree.children(parent)?;
+        let old_child = tree.child_at_index(parent, child_index)?;
+        if old_child == new_child {
+            return Ok(old_child);
+        }
+        // Taking a sibling out from before the index shifts the old child
+        // down by one.
+        if children[..child_index].contains(&new_child) {
+            child_index -= 1;
+        }
+        tree.detach(new_child)?;
+        Ok(tree
+            .taffy
+            .replace_child_at_index(parent, child_index, new_child)?)
     }
 
     pub fn set_children(&self, parent: NodeId, children: std::vec::Vec<NodeId>) -> TaffyResult<()> {
-        Ok(self.0.write()?.set_children(parent, &children)?)
+        let mut tree = self.write()?;
+        tree.check_parent(parent)?;
+        tree.check_children(Some(parent), &children)?;
+        for &child in &children {
+            if tree.parent(child) != Some(parent) {
+                tree.detach(child)?;
+            }
+        }
+        Ok(tree.taffy.set_children(parent, &children)?)
+    }
+
+    pub fn set_node_context(&self, node: NodeId, context: Option<NodeContext>) -> TaffyResult<()> {
+        self.update_node_data(node, |data| data.context = context)
+    }
+
+    pub fn set_measure_function(
+        &self,
+        node: NodeId,
+        measure_function: Box<dyn MeasureFunction>,
+    ) -> TaffyResult<()> {
+        self.update_node_data(node, |data| data.measure_function = Some(measure_function))
+    }
+
+    pub fn clear_measure_function(&self, node: NodeId) -> TaffyResult<()> {
+        self.update_node_data(node, |data| data.measure_function = None)
     }
 
-    pub fn set_node_context(&self, node: NodeId) -> TaffyResult<()> {
-        Ok(self.0.write()?.set_node_context(node, Some(()))?)
+    pub fn has_measure_function(&self, node: NodeId) -> TaffyResult<bool> {
+        let tree = self.read()?;
+        tree.check_node(node)?;
+        Ok(tree
+            .get_node_context(node)
+            .is_some_and(|data| data.measure_function.is_some()))
     }
 
     pub fn set_style(&self, node: NodeId, style: std::sync::Arc<Style>) -> TaffyResult<()> {
-        Ok(self.0.write()?.set_style(node, style.0.read()?.clone())?)
+        let mut tree = self.write()?;
+        let style = tree.style_validation.style(style.0.read()?.clone())?;
+        tree.check_node(node)?;
+        Ok(tree.taffy.set_style(node, style)?)
     }
 
     pub fn get_style(&self, node: NodeId) -> TaffyResult<std::sync::Arc<Style>> {
-        Ok(Arc::new(self.0.read()?.style(node)?.to_owned().into()))
+        let tree = self.read()?;
+        tree.check_node(node)?;
+        Ok(Arc::new(tree.style(node)?.to_owned().into()))
+    }
+
+    pub fn set_style_data(&self, node: NodeId, style: StyleData) -> TaffyResult<()> {
+        let mut tree = self.write()?;
+        let style = tree.style_validation.style(style.convert())?;
+        tree.check_node(node)?;
+        Ok(tree.taffy.set_style(node, style)?)
+    }
+
+    pub fn get_style_data(&self, node: NodeId) -> TaffyResult<StyleData> {
+        let tree = self.read()?;
+        tree.check_node(node)?;
+        Ok(tree.style(node)?.convert())
     }
 
     pub fn get_total_node_count(&self) -> TaffyResult<u64> {
-        Ok(self.0.read()?.total_node_count() as u64)
+        Ok(self.read()?.total_node_count() as u64)
+    }
+
+    fn read(&self) -> TaffyResult<RwLockReadGuard<'_, Tree>> {
+        self.check_not_computing()?;
+        Ok(self.0.read()?)
+    }
+
+    fn write(&self) -> TaffyResult<RwLockWriteGuard<'_, Tree>> {
+        self.check_not_computing()?;
+        Ok(self.0.write()?)
+    }
+
+    fn check_not_computing(&self) -> TaffyResult<()> {
+        let address = self as *const Self as usize;
+        if COMPUTING.with(|computing| computing.borrow().contains(&address)) {
+            Err(TaffyError::LockContention)
+        } else {
+            Ok(())
+        }
+    }
+
+    // Runs a layout pass. taffy still panics on some inputs, and a panic
+    // unwinding through the lock would poison it, so it's caught here and
+    // reported like any other error. Other calls check their input before it
+    // reaches taffy and aren't wrapped.
+    fn compute<R>(
+        &self,
+        node: NodeId,
+        compute: impl FnOnce(&mut taffy::TaffyTree<NodeData>) -> TaffyResult<R>,
+    ) -> TaffyResult<R> {
+        let mut tree = self.write()?;
+        tree.check_node(node)?;
+        let address = self as *const Self as usize;
+        COMPUTING.with(|computing| computing.borrow_mut().push(address));
+        let _guard = ComputingGuard(address);
+        panic::catch_unwind(AssertUnwindSafe(|| compute(&mut tree.taffy))).unwrap_or_else(
+            |payload| {
+                // The pass stopped halfway, so none of the cached results can
+                // be trusted anymore.
+                tree.invalidate_layouts()?;
+                Err(TaffyError::from_panic(payload))
+            },
+        )
+    }
+
+    fn export_document(&self, root: NodeId, include_layouts: bool) -> TaffyResult<TreeDocument> {
+        let tree = self.read()?;
+        tree.check_node(root)?;
+        let mut nodes: Vec<NodeDocument> = Vec::new();
+        let mut stack: Vec<(NodeId, Option<usize>)> = vec![(root, None)];
+        while let Some((node, parent)) = stack.pop() {
+            let index = nodes.len();
+            if let Some(parent) = parent {
+                nodes[parent].children.push(index);
+            }
+            nodes.push(NodeDocument {
+                style: tree.style(node)?.clone(),
+                context: tree
+                    .get_node_context(node)
+                    .and_then(|data| data.context.clone()),
+                layout: include_layouts
+                    .then(|| tree.layout(node).map(LayoutDocument::from))
+                    .transpose()?,
+                children: Vec::new(),
+            });
+            stack.extend(
+                tree.children(node)?
+                    .into_iter()
+                    .rev()
+                    .map(|child| (child, Some(index))),
+            );
+        }
+        Ok(TreeDocument::new(nodes))
+    }
+
+    fn import_document(&self, document: TreeDocument) -> TaffyResult<NodeId> {
+        Ok(build_document(&mut *self.write()?, document)?[0])
+    }
+
+    fn query_nodes(
+        &self,
+        root: NodeId,
+        hits: impl Fn(&Rect<f32>) -> bool,
+    ) -> TaffyResult<Vec<NodeId>> {
+        let tree = self.read()?;
+        tree.check_node(root)?;
+        let unbounded = Rect {
+            left: f32::NEG_INFINITY,
+            right: f32::INFINITY,
+            top: f32::NEG_INFINITY,
+            bottom: f32::INFINITY,
+        };
+        let mut nodes = Vec::new();
+        collect_hits(
+            &tree,
+            root,
+            absolute_location(&tree, root)?,
+            unbounded,
+            &hits,
+            &mut nodes,
+        )?;
+        Ok(nodes)
+    }
+
+    fn update_node_data(
+        &self,
+        node: NodeId,
+        update: impl FnOnce(&mut NodeData),
+    ) -> TaffyResult<()> {
+        let mut tree = self.write()?;
+        tree.check_node(node)?;
+        // Going through set_node_context marks the node dirty and drops the
+        // entry once nothing is attached to the node anymore.
+        let mut data = tree
+            .taffy
+            .get_node_context_mut(node)
+            .map(std::mem::take)
+            .unwrap_or_default();
+        update(&mut data);
+        Ok(tree
+            .taffy
+            .set_node_context(node, (!data.is_empty()).then_some(data))?)
+    }
+}
+
+// Creates the nodes of a validated document, returning their ids in document
+// order. Layouts are recomputed rather than restored.
+fn build_document(tree: &mut Tree, mut document: TreeDocument) -> TaffyResult<Vec<NodeId>> {
+    // Styles are checked up front so that an invalid one can't leave the
+    // nodes built so far behind.
+    for node in &mut document.nodes {
+        node.style = tree
+            .style_validation
+            .style(std::mem::take(&mut node.style))?;
+    }
+    // Children come after their parent, so building back to front always
+    // finds them created.
+    let mut ids = vec![NodeId::from(0u64); document.nodes.len()];
+    for (index, node) in document.nodes.into_iter().enumerate().rev() {
+        let children: Vec<NodeId> = node.children.iter().map(|&child| ids[child]).collect();
+        ids[index] = tree.taffy.new_with_children(node.style, &children)?;
+        tree.add(ids[index]);
+        if let Some(context) = node.context {
+            tree.taffy
+                .set_node_context(ids[index], Some(NodeData::with_context(context)))?;
+        }
+    }
+    Ok(ids)
+}
+
+fn scroll_offset(tree: &Tree, node: NodeId) -> Point<f32> {
+    tree.scroll_offsets
+        .get(&node)
+        .copied()
+        .unwrap_or(Point::ZERO)
+}
+
+// Where the children of `node` are placed from, given the absolute location of
+// `node` itself.
+fn content_origin(tree: &Tree, node: NodeId, location: Point<f32>) -> Point<f32> {
+    let scroll_offset = scroll_offset(tree, node);
+    Point {
+        x: location.x - scroll_offset.x,
+        y: location.y - scroll_offset.y,
+    }
+}
+
+fn absolute_location(tree: &Tree, node: NodeId) -> TaffyResult<Point<f32>> {
+    let mut location = tree.layout(node)?.location;
+    let mut current = node;
+    while let Some(parent) = tree.parent(current) {
+        location = content_origin(tree, parent, location + tree.layout(parent)?.location);
+        current = parent;
+    }
+    Ok(location)
+}
+
+// Pushes the nodes of the subtree whose visible part of the border box is hit,
+// front to back: children before their parent and later siblings first.
+fn collect_hits(
+    tree: &Tree,
+    root: NodeId,
+    location: Point<f32>,
+    clip: Rect<f32>,
+    hits: &impl Fn(&Rect<f32>) -> bool,
+    nodes: &mut Vec<NodeId>,
+) -> TaffyResult<()> {
+    enum Visit {
+        Enter(NodeId, Point<f32>, Rect<f32>),
+        // Once its children are done, a node is tested against its border box
+        // cut down to the clip it was entered with.
+        Exit(NodeId, Rect<f32>),
+    }
+
+    let mut stack = vec![Visit::Enter(root, location, clip)];
+    while let Some(visit) = stack.pop() {
+        let (node, location, clip) = match visit {
+            Visit::Enter(node, location, clip) => (node, location, clip),
+            Visit::Exit(node, visible) => {
+                if visible.left < visible.right && visible.top < visible.bottom && hits(&visible) {
+                    nodes.push(node);
+                }
+                continue;
+            }
+        };
+
+        let style = tree.style(node)?;
+        if style.display == Display::None {
+            continue;
+        }
+
+        let layout = tree.layout(node)?;
+        let border_box = Rect {
+            left: location.x,
+            right: location.x + layout.size.width,
+            top: location.y,
+            bottom: location.y + layout.size.height,
+        };
+
+        let mut child_clip = clip;
+        if clips_content(style.overflow.x) {
+            child_clip.left = child_clip.left.max(border_box.left + layout.border.left);
+            child_clip.right = child_clip.right.min(border_box.right - layout.border.right);
+        }
+        if clips_content(style.overflow.y) {
+            child_clip.top = child_clip.top.max(border_box.top + layout.border.top);
+            child_clip.bottom = child_clip
+                .bottom
+                .min(border_box.bottom - layout.border.bottom);
+        }
+
+        stack.push(Visit::Exit(
+            node,
+            Rect {
+                left: border_box.left.max(clip.left),
+                right: border_box.right.min(clip.right),
+                top: border_box.top.max(clip.top),
+                bottom: border_box.bottom.min(clip.bottom),
+            },
+        ));
+        // The last child is pushed last so that it's visited first.
+        let origin = content_origin(tree, node, location);
+        for child in tree.children(node)? {
+            let child_location = origin + tree.layout(child)?.location;
+            stack.push(Visit::Enter(child, child_location, child_clip));
+        }
+    }
+    Ok(())
+}
+
+fn clips_content(overflow: Overflow) -> bool {
+    matches!(
+        overflow,
+        Overflow::Clip | Overflow::Hidden | Overflow::Scroll
+    )
+}
+
+// Same output as taffy's print_tree, which only knows how to write to stdout.
+fn write_debug_tree(tree: &Tree, root: NodeId, output: &mut String) -> TaffyResult<()> {
+    let mut stack = vec![(root, false, String::new())];
+    while let Some((node, has_sibling, lines)) = stack.pop() {
+        let layout = tree.layout(node)?;
+        let fork = if has_sibling {
+            "├── "
+        } else {
+            "└── "
+        };
+        let _ = writeln!(
+            output,
+            "{lines}{fork} {display} [x: {x:<4} y: {y:<4} w: {width:<4} h: {height:<4} content_w: {content_width:<4} content_h: {content_height:<4} border: l:{bl} r:{br} t:{bt} b:{bb}, padding: l:{pl} r:{pr} t:{pt} b:{pb}] ({node:?})",
+            display = tree.get_debug_label(node),
+            x = layout.location.x,
+            y = layout.location.y,
+            width = layout.size.width,
+            height = layout.size.height,
+            content_width = layout.content_size.width,
+            content_height = layout.content_size.height,
+            bl = layout.border.left,
+            br = layout.border.right,
+            bt = layout.border.top,
+            bb = layout.border.bottom,
+            pl = layout.padding.left,
+            pr = layout.padding.right,
+            pt = layout.padding.top,
+            pb = layout.padding.bottom,
+        );
+
+        let bar = if has_sibling { "│   " } else { "    " };
+        let children = tree.children(node)?;
+        for (index, child) in children.iter().enumerate().rev() {
+            let has_sibling = index < children.len() - 1;
+            stack.push((*child, has_sibling, lines.clone() + bar));
+        }
+    }
+    Ok(())
+}
+
+pub struct DebugNode {
+    pub node: NodeId,
+    pub depth: u32,
+    pub label: String,
+    pub style: StyleData,
+    pub layout: Layout,
+}
+
+pub struct NodeLayout {
+    pub node: NodeId,
+    pub layout: Layout,
+}
+
+impl Default for TaffyTree {
+    fn default() -> Self {
+        Self::new()
     }
 }
 
-pub trait MeasureFunction {
+pub trait MeasureFunction: Send + Sync {
     fn measure(
         &self,
         known_size: Size<Option<f32>>,
         avaliable_space: Size<AvailableSpace>,
         node: NodeId,
-    ) -> Size<f32>;
+        context: Option<NodeContext>,
+    ) -> Result<Size<f32>, MeasureError>;
 }
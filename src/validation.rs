@@ -0,0 +1,325 @@
+use std::fmt::Debug;
+
+use taffy::{
+    Dimension, GridTrackRepetition, LengthPercentage, LengthPercentageAuto, MaxTrackSizingFunction,
+    MinTrackSizingFunction, NonRepeatedTrackSizingFunction, Rect, Size, TrackSizingFunction,
+};
+
+use crate::{TaffyError, TaffyResult};
+
+// Each style and each tree has its own mode, so that one part of a program
+// can't change how another part's styles are checked.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum StyleValidation {
+    #[default]
+    Strict,
+    Lenient,
+}
+
+#[derive(Clone, Copy)]
+enum Sign {
+    Any,
+    NonNegative,
+}
+
+impl StyleValidation {
+    // Checks every numeric value of a style the way the matching setters do.
+    pub(crate) fn style(self, style: taffy::Style) -> TaffyResult<taffy::Style> {
+        Ok(taffy::Style {
+            scrollbar_width: self.scrollbar_width(style.scrollbar_width)?,
+            inset: self.inset(style.inset)?,
+            size: self.size(style.size)?,
+            min_size: self.min_size(style.min_size)?,
+            max_size: self.max_size(style.max_size)?,
+            aspect_ratio: self.aspect_ratio(style.aspect_ratio)?,
+            margin: self.margin(style.margin)?,
+            padding: self.padding(style.padding)?,
+            border: self.border(style.border)?,
+            gap: self.gap(style.gap)?,
+            flex_basis: self.flex_basis(style.flex_basis)?,
+            flex_grow: self.flex_grow(style.flex_grow)?,
+            flex_shrink: self.flex_shrink(style.flex_shrink)?,
+            grid_template_rows: self.tracks("grid-template-rows", style.grid_template_rows)?,
+            grid_template_columns: self
+                .tracks("grid-template-columns", style.grid_template_columns)?,
+            grid_auto_rows: self.track_sizes("grid-auto-rows", style.grid_auto_rows)?,
+            grid_auto_columns: self.track_sizes("grid-auto-columns", style.grid_auto_columns)?,
+            ..style
+        })
+    }
+
+    pub(crate) fn scrollbar_width(self, value: f32) -> TaffyResult<f32> {
+        self.number("scrollbar-width", value, Sign::NonNegative)
+    }
+
+    pub(crate) fn inset(
+        self,
+        value: Rect<LengthPercentageAuto>,
+    ) -> TaffyResult<Rect<LengthPercentageAuto>> {
+        self.sides(
+            ["left", "right", "top", "bottom"],
+            value,
+            |property, value| self.length_percentage_auto(property, value, Sign::Any),
+        )
+    }
+
+    pub(crate) fn size(self, value: Size<Dimension>) -> TaffyResult<Size<Dimension>> {
+        self.axes(["width", "height"], value, |property, value| {
+            self.dimension(property, value, Sign::NonNegative)
+        })
+    }
+
+    pub(crate) fn min_size(self, value: Size<Dimension>) -> TaffyResult<Size<Dimension>> {
+        self.axes(["min-width", "min-height"], value, |property, value| {
+            self.dimension(property, value, Sign::NonNegative)
+        })
+    }
+
+    pub(crate) fn max_size(self, value: Size<Dimension>) -> TaffyResult<Size<Dimension>> {
+        self.axes(["max-width", "max-height"], value, |property, value| {
+            self.dimension(property, value, Sign::NonNegative)
+        })
+    }
+
+    // A ratio has to be positive; anything else is dropped rather than clamped.
+    pub(crate) fn aspect_ratio(self, value: Option<f32>) -> TaffyResult<Option<f32>> {
+        match value {
+            Some(ratio) if !(ratio.is_finite() && ratio > 0.0) => {
+                self.reject("aspect-ratio", ratio, None)
+            }
+            value => Ok(value),
+        }
+    }
+
+    pub(crate) fn margin(
+        self,
+        value: Rect<LengthPercentageAuto>,
+    ) -> TaffyResult<Rect<LengthPercentageAuto>> {
+        self.sides(
+            ["margin-left", "margin-right", "margin-top", "margin-bottom"],
+            value,
+            |property, value| self.length_percentage_auto(property, value, Sign::Any),
+        )
+    }
+
+    pub(crate) fn padding(
+        self,
+        value: Rect<LengthPercentage>,
+    ) -> TaffyResult<Rect<LengthPercentage>> {
+        self.sides(
+            [
+                "padding-left",
+                "padding-right",
+                "padding-top",
+                "padding-bottom",
+            ],
+            value,
+            |property, value| self.length_percentage(property, value, Sign::NonNegative),
+        )
+    }
+
+    pub(crate) fn border(
+        self,
+        value: Rect<LengthPercentage>,
+    ) -> TaffyResult<Rect<LengthPercentage>> {
+        self.sides(
+            [
+                "border-left-width",
+                "border-right-width",
+                "border-top-width",
+                "border-bottom-width",
+            ],
+            value,
+            |property, value| self.length_percentage(property, value, Sign::NonNegative),
+        )
+    }
+
+    pub(crate) fn gap(self, value: Size<LengthPercentage>) -> TaffyResult<Size<LengthPercentage>> {
+        self.axes(["column-gap", "row-gap"], value, |property, value| {
+            self.length_percentage(property, value, Sign::NonNegative)
+        })
+    }
+
+    pub(crate) fn flex_basis(self, value: Dimension) -> TaffyResult<Dimension> {
+        self.dimension("flex-basis", value, Sign::NonNegative)
+    }
+
+    pub(crate) fn flex_grow(self, value: f32) -> TaffyResult<f32> {
+        self.number("flex-grow", value, Sign::NonNegative)
+    }
+
+    pub(crate) fn flex_shrink(self, value: f32) -> TaffyResult<f32> {
+        self.number("flex-shrink", value, Sign::NonNegative)
+    }
+
+    pub(crate) fn tracks(
+        self,
+        property: &str,
+        value: Vec<TrackSizingFunction>,
+    ) -> TaffyResult<Vec<TrackSizingFunction>> {
+        value
+            .into_iter()
+            .filter_map(|track| self.track(property, track).transpose())
+            .collect()
+    }
+
+    // CSS has no form for repeating zero times or repeating no tracks, so
+    // lenient mode drops those repeats, which add no tracks anyway.
+    fn track(
+        self,
+        property: &str,
+        value: TrackSizingFunction,
+    ) -> TaffyResult<Option<TrackSizingFunction>> {
+        match value {
+            TrackSizingFunction::Single(size) => Ok(Some(TrackSizingFunction::Single(
+                self.track_size(property, size)?,
+            ))),
+            TrackSizingFunction::Repeat(GridTrackRepetition::Count(0), _) => {
+                self.reject(property, 0.0, None)
+            }
+            TrackSizingFunction::Repeat(_, sizes) if sizes.is_empty() => {
+                self.reject(property, 0.0, None)
+            }
+            TrackSizingFunction::Repeat(repetition, sizes) => Ok(Some(
+                TrackSizingFunction::Repeat(repetition, self.track_sizes(property, sizes)?),
+            )),
+        }
+    }
+
+    pub(crate) fn track_sizes(
+        self,
+        property: &str,
+        value: Vec<NonRepeatedTrackSizingFunction>,
+    ) -> TaffyResult<Vec<NonRepeatedTrackSizingFunction>> {
+        value
+            .into_iter()
+            .map(|size| self.track_size(property, size))
+            .collect()
+    }
+
+    fn track_size(
+        self,
+        property: &str,
+        value: NonRepeatedTrackSizingFunction,
+    ) -> TaffyResult<NonRepeatedTrackSizingFunction> {
+        let min = match value.min {
+            MinTrackSizingFunction::Fixed(value) => MinTrackSizingFunction::Fixed(
+                self.length_percentage(property, value, Sign::NonNegative)?,
+            ),
+            min => min,
+        };
+        let max = match value.max {
+            MaxTrackSizingFunction::Fixed(value) => MaxTrackSizingFunction::Fixed(
+                self.length_percentage(property, value, Sign::NonNegative)?,
+            ),
+            MaxTrackSizingFunction::FitContent(value) => MaxTrackSizingFunction::FitContent(
+                self.length_percentage(property, value, Sign::NonNegative)?,
+            ),
+            MaxTrackSizingFunction::Fraction(value) => {
+                MaxTrackSizingFunction::Fraction(self.number(property, value, Sign::NonNegative)?)
+            }
+            max => max,
+        };
+        Ok(NonRepeatedTrackSizingFunction { min, max })
+    }
+
+    fn sides<T>(
+        self,
+        properties: [&str; 4],
+        value: Rect<T>,
+        validate: impl Fn(&str, T) -> TaffyResult<T>,
+    ) -> TaffyResult<Rect<T>> {
+        Ok(Rect {
+            left: validate(properties[0], value.left)?,
+            right: validate(properties[1], value.right)?,
+            top: validate(properties[2], value.top)?,
+            bottom: validate(properties[3], value.bottom)?,
+        })
+    }
+
+    fn axes<T>(
+        self,
+        properties: [&str; 2],
+        value: Size<T>,
+        validate: impl Fn(&str, T) -> TaffyResult<T>,
+    ) -> TaffyResult<Size<T>> {
+        Ok(Size {
+            width: validate(properties[0], value.width)?,
+            height: validate(properties[1], value.height)?,
+        })
+    }
+
+    fn dimension(self, property: &str, value: Dimension, sign: Sign) -> TaffyResult<Dimension> {
+        Ok(match value {
+            Dimension::Length(value) => Dimension::Length(self.number(property, value, sign)?),
+            Dimension::Percent(value) => Dimension::Percent(self.number(property, value, sign)?),
+            Dimension::Auto => Dimension::Auto,
+        })
+    }
+
+    fn length_percentage(
+        self,
+        property: &str,
+        value: LengthPercentage,
+        sign: Sign,
+    ) -> TaffyResult<LengthPercentage> {
+        Ok(match value {
+            LengthPercentage::Length(value) => {
+                LengthPercentage::Length(self.number(property, value, sign)?)
+            }
+            LengthPercentage::Percent(value) => {
+                LengthPercentage::Percent(self.number(property, value, sign)?)
+            }
+        })
+    }
+
+    fn length_percentage_auto(
+        self,
+        property: &str,
+        value: LengthPercentageAuto,
+        sign: Sign,
+    ) -> TaffyResult<LengthPercentageAuto> {
+        Ok(match value {
+            LengthPercentageAuto::Length(value) => {
+                LengthPercentageAuto::Length(self.number(property, value, sign)?)
+            }
+            LengthPercentageAuto::Percent(value) => {
+                LengthPercentageAuto::Percent(self.number(property, value, sign)?)
+            }
+            LengthPercentageAuto::Auto => LengthPercentageAuto::Auto,
+        })
+    }
+
+    // Lenient mode clamps into range, with NaN becoming 0.
+    fn number(self, property: &str, value: f32, sign: Sign) -> TaffyResult<f32> {
+        let min = match sign {
+            Sign::Any => f32::MIN,
+            Sign::NonNegative => 0.0,
+        };
+        if value.is_finite() && value >= min {
+            return Ok(value);
+        }
+        let clamped = if value.is_nan() {
+            0.0
+        } else {
+            value.clamp(min, f32::MAX)
+        };
+        self.reject(property, value, clamped)
+    }
+
+    fn reject<T: Debug>(self, property: &str, value: f32, replacement: T) -> TaffyResult<T> {
+        match self {
+            StyleValidation::Strict => Err(TaffyError::InvalidStyleValue {
+                property: property.to_owned(),
+                value,
+            }),
+            StyleValidation::Lenient => {
+                log::warn!(
+                    "invalid value {value} for style property {property}, \
+                     using {replacement:?} instead"
+                );
+                Ok(replacement)
+            }
+        }
+    }
+}
@@ -0,0 +1,55 @@
+use taffy::Style;
+
+// Copies every property of a style through its getter and matching setter.
+pub fn copy_style(style: &Style) -> Style {
+    let copy = Style::new();
+    copy.set_display(style.get_display().unwrap()).unwrap();
+    copy.set_overflow(style.get_overflow().unwrap()).unwrap();
+    copy.set_scrollbar_width(style.get_scrollbar_width().unwrap())
+        .unwrap();
+    copy.set_position(style.get_position().unwrap()).unwrap();
+    copy.set_inset(style.get_inset().unwrap()).unwrap();
+    copy.set_size(style.get_size().unwrap()).unwrap();
+    copy.set_min_size(style.get_min_size().unwrap()).unwrap();
+    copy.set_max_size(style.get_max_size().unwrap()).unwrap();
+    copy.set_aspect_ratio(style.get_aspect_ratio().unwrap())
+        .unwrap();
+    copy.set_margin(style.get_margin().unwrap()).unwrap();
+    copy.set_padding(style.get_padding().unwrap()).unwrap();
+    copy.set_border(style.get_border().unwrap()).unwrap();
+    copy.set_align_items(style.get_align_items().unwrap())
+        .unwrap();
+    copy.set_align_self(style.get_align_self().unwrap())
+        .unwrap();
+    copy.set_justify_items(style.get_justify_items().unwrap())
+        .unwrap();
+    copy.set_justify_self(style.get_justify_self().unwrap())
+        .unwrap();
+    copy.set_align_content(style.get_align_content().unwrap())
+        .unwrap();
+    copy.set_justify_content(style.get_justify_content().unwrap())
+        .unwrap();
+    copy.set_gap(style.get_gap().unwrap()).unwrap();
+    copy.set_flex_direction(style.get_flex_direction().unwrap())
+        .unwrap();
+    copy.set_flex_wrap(style.get_flex_wrap().unwrap()).unwrap();
+    copy.set_flex_basis(style.get_flex_basis().unwrap())
+        .unwrap();
+    copy.set_flex_grow(style.get_flex_grow().unwrap()).unwrap();
+    copy.set_flex_shrink(style.get_flex_shrink().unwrap())
+        .unwrap();
+    copy.set_grid_template_rows(style.get_grid_template_rows().unwrap())
+        .unwrap();
+    copy.set_grid_template_columns(style.get_grid_template_columns().unwrap())
+        .unwrap();
+    copy.set_grid_auto_rows(style.get_grid_auto_rows().unwrap())
+        .unwrap();
+    copy.set_grid_auto_columns(style.get_grid_auto_columns().unwrap())
+        .unwrap();
+    copy.set_grid_auto_flow(style.get_grid_auto_flow().unwrap())
+        .unwrap();
+    copy.set_grid_row(style.get_grid_row().unwrap()).unwrap();
+    copy.set_grid_column(style.get_grid_column().unwrap())
+        .unwrap();
+    copy
+}
@@ -0,0 +1,96 @@
+// Runs a subset of taffy's HTML test fixtures through the bindings and checks
+// every node's layout against the one recorded next to the fixture, which is
+// what taffy's generated tests assert: each node's location and size, in
+// pre-order. Each fixture is checked as imported, and again after every style
+// has been copied through the public getters and setters.
+//
+// A fixture added to tests/fixtures needs its layouts in a JSON file of the
+// same name.
+
+mod common;
+
+use std::{fs, path::Path, sync::Arc};
+
+use serde::Deserialize;
+use taffy::{AvailableSpace, NodeId, SizeAvailableSpace, TaffyTree};
+
+const MAX_CONTENT: SizeAvailableSpace = SizeAvailableSpace {
+    width: AvailableSpace::MaxContent,
+    height: AvailableSpace::MaxContent,
+};
+
+const EPSILON: f32 = 0.001;
+
+#[derive(Deserialize)]
+struct ExpectedLayout {
+    x: f32,
+    y: f32,
+    width: f32,
+    height: f32,
+}
+
+#[test]
+fn fixtures() {
+    let directory = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures");
+    let mut paths: Vec<_> = fs::read_dir(directory)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| {
+            path.extension()
+                .is_some_and(|extension| extension == "html")
+        })
+        .collect();
+    paths.sort();
+    assert!(!paths.is_empty());
+
+    for path in paths {
+        let name = path.file_stem().unwrap().to_string_lossy();
+        let html = fs::read_to_string(&path).unwrap();
+        let layouts = fs::read_to_string(path.with_extension("json"))
+            .unwrap_or_else(|error| panic!("{name}: no expected layouts: {error}"));
+        let expected: Vec<ExpectedLayout> = serde_json::from_str(&layouts).unwrap();
+        run_fixture(&name, html, &expected);
+    }
+}
+
+fn run_fixture(name: &str, html: String, expected: &[ExpectedLayout]) {
+    let tree = TaffyTree::new();
+    let root = tree
+        .from_html_fixture(html)
+        .unwrap_or_else(|error| panic!("{name}: {error}"));
+    tree.compute_layout(root, MAX_CONTENT).unwrap();
+    assert_layouts_match(&format!("{name} as imported"), &tree, root, expected);
+
+    for (index, node) in tree.get_layouts(root).unwrap().iter().enumerate() {
+        let style = tree.get_style(node.node).unwrap();
+        let copy = Arc::new(common::copy_style(&style));
+        assert_eq!(
+            copy.to_css().unwrap(),
+            style.to_css().unwrap(),
+            "{name}: node {index} copied through getters"
+        );
+        tree.set_style(node.node, copy).unwrap();
+    }
+    tree.compute_layout(root, MAX_CONTENT).unwrap();
+    assert_layouts_match(&format!("{name} with copied styles"), &tree, root, expected);
+}
+
+fn assert_layouts_match(name: &str, tree: &TaffyTree, root: NodeId, expected: &[ExpectedLayout]) {
+    let actual = tree.get_layouts(root).unwrap();
+    assert_eq!(actual.len(), expected.len(), "{name}: node count");
+    for (index, (actual, expected)) in actual.iter().zip(expected).enumerate() {
+        let layout = &actual.layout;
+        let fields = [
+            ("x", layout.location.x, expected.x),
+            ("y", layout.location.y, expected.y),
+            ("width", layout.size.width, expected.width),
+            ("height", layout.size.height, expected.height),
+        ];
+        for (field, actual, expected) in fields {
+            assert!(
+                (actual - expected).abs() < EPSILON,
+                "{name}: node {index}: {field} is {actual}, expected {expected}"
+            );
+        }
+    }
+}
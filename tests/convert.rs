@@ -0,0 +1,452 @@
+// Round-trips arbitrary taffy styles through the binding types: into a Style,
+// out through every getter and back in through every setter, through CSS and
+// through StyleData. Styles are compared by their Debug output, so that NaN compares
+// equal to itself while 0.0 and -0.0 stay distinct.
+//
+// Every value round-trips unchanged except for grid line 0 and span 0, which
+// are invalid in CSS and make taffy panic during layout. Setting either one
+// stores `Auto` instead, so they're left out of the generated styles and
+// covered by the tests at the end. The setters and `Style::from_data` reject
+// NaN, infinities, negative sizes and repeat()s of no tracks or zero times, so
+// only valid styles round-trip through them. Styles with any value are checked
+// to be either kept exactly or rejected, and to come out valid from a lenient
+// tree. Negative spans can't be expressed on either side, as spans are u16 in
+// both taffy and the bindings.
+
+mod common;
+
+use std::sync::Arc;
+
+use proptest::{collection::vec, option, prelude::*};
+use taffy::{
+    AvailableSpace, GridPlacement, LineGridPlacement, SizeAvailableSpace, Style, StyleValidation,
+    TaffyError, TaffyTree,
+};
+use taffy_core::{
+    style_helpers::TaffyGridLine, AlignContent, AlignItems, Dimension, Display, FlexDirection,
+    FlexWrap, GridAutoFlow, GridTrackRepetition, LengthPercentage, LengthPercentageAuto, Line,
+    MaxTrackSizingFunction, MinTrackSizingFunction, NonRepeatedTrackSizingFunction, Overflow,
+    Point, Position, Rect, Size, TrackSizingFunction,
+};
+
+fn assert_same(actual: &taffy_core::Style, expected: &taffy_core::Style) {
+    assert_eq!(format!("{actual:?}"), format!("{expected:?}"));
+}
+
+// The floats to generate for a property.
+#[derive(Clone, Copy)]
+enum Floats {
+    Any,
+    Finite,
+    NonNegative,
+    Positive,
+}
+
+// Every property takes any float unless the style has to be valid.
+fn floats(valid: bool, floats: Floats) -> Floats {
+    if valid {
+        floats
+    } else {
+        Floats::Any
+    }
+}
+
+fn float(floats: Floats) -> BoxedStrategy<f32> {
+    match floats {
+        Floats::Any => prop_oneof![
+            -1000.0f32..1000.0,
+            Just(0.0),
+            Just(-0.0),
+            Just(f32::INFINITY),
+            Just(f32::NEG_INFINITY),
+            Just(f32::NAN),
+        ]
+        .boxed(),
+        Floats::Finite => prop_oneof![-1000.0f32..1000.0, Just(0.0), Just(-0.0)].boxed(),
+        Floats::NonNegative => prop_oneof![0.0f32..1000.0, Just(0.0), Just(-0.0)].boxed(),
+        Floats::Positive => (0.001f32..1000.0).boxed(),
+    }
+}
+
+fn display() -> BoxedStrategy<Display> {
+    prop_oneof![
+        Just(Display::Block),
+        Just(Display::Flex),
+        Just(Display::Grid),
+        Just(Display::None),
+    ]
+    .boxed()
+}
+
+fn overflow() -> BoxedStrategy<Overflow> {
+    prop_oneof![
+        Just(Overflow::Visible),
+        Just(Overflow::Clip),
+        Just(Overflow::Hidden),
+        Just(Overflow::Scroll),
+    ]
+    .boxed()
+}
+
+fn position() -> BoxedStrategy<Position> {
+    prop_oneof![Just(Position::Relative), Just(Position::Absolute)].boxed()
+}
+
+fn align_items() -> BoxedStrategy<AlignItems> {
+    prop_oneof![
+        Just(AlignItems::Start),
+        Just(AlignItems::End),
+        Just(AlignItems::FlexStart),
+        Just(AlignItems::FlexEnd),
+        Just(AlignItems::Center),
+        Just(AlignItems::Baseline),
+        Just(AlignItems::Stretch),
+    ]
+    .boxed()
+}
+
+fn align_content() -> BoxedStrategy<AlignContent> {
+    prop_oneof![
+        Just(AlignContent::Start),
+        Just(AlignContent::End),
+        Just(AlignContent::FlexStart),
+        Just(AlignContent::FlexEnd),
+        Just(AlignContent::Center),
+        Just(AlignContent::Stretch),
+        Just(AlignContent::SpaceBetween),
+        Just(AlignContent::SpaceEvenly),
+        Just(AlignContent::SpaceAround),
+    ]
+    .boxed()
+}
+
+fn flex_direction() -> BoxedStrategy<FlexDirection> {
+    prop_oneof![
+        Just(FlexDirection::Row),
+        Just(FlexDirection::Column),
+        Just(FlexDirection::RowReverse),
+        Just(FlexDirection::ColumnReverse),
+    ]
+    .boxed()
+}
+
+fn flex_wrap() -> BoxedStrategy<FlexWrap> {
+    prop_oneof![
+        Just(FlexWrap::NoWrap),
+        Just(FlexWrap::Wrap),
+        Just(FlexWrap::WrapReverse),
+    ]
+    .boxed()
+}
+
+fn grid_auto_flow() -> BoxedStrategy<GridAutoFlow> {
+    prop_oneof![
+        Just(GridAutoFlow::Row),
+        Just(GridAutoFlow::Column),
+        Just(GridAutoFlow::RowDense),
+        Just(GridAutoFlow::ColumnDense),
+    ]
+    .boxed()
+}
+
+fn dimension(floats: Floats) -> BoxedStrategy<Dimension> {
+    prop_oneof![
+        float(floats).prop_map(Dimension::Length),
+        float(floats).prop_map(Dimension::Percent),
+        Just(Dimension::Auto),
+    ]
+    .boxed()
+}
+
+fn length_percentage(floats: Floats) -> BoxedStrategy<LengthPercentage> {
+    prop_oneof![
+        float(floats).prop_map(LengthPercentage::Length),
+        float(floats).prop_map(LengthPercentage::Percent),
+    ]
+    .boxed()
+}
+
+fn length_percentage_auto(floats: Floats) -> BoxedStrategy<LengthPercentageAuto> {
+    prop_oneof![
+        float(floats).prop_map(LengthPercentageAuto::Length),
+        float(floats).prop_map(LengthPercentageAuto::Percent),
+        Just(LengthPercentageAuto::Auto),
+    ]
+    .boxed()
+}
+
+fn size<T: std::fmt::Debug + 'static>(
+    value: impl Strategy<Value = T> + Clone + 'static,
+) -> BoxedStrategy<Size<T>> {
+    (value.clone(), value)
+        .prop_map(|(width, height)| Size { width, height })
+        .boxed()
+}
+
+fn rect<T: std::fmt::Debug + 'static>(
+    value: impl Strategy<Value = T> + Clone + 'static,
+) -> BoxedStrategy<Rect<T>> {
+    (value.clone(), value.clone(), value.clone(), value)
+        .prop_map(|(left, right, top, bottom)| Rect {
+            left,
+            right,
+            top,
+            bottom,
+        })
+        .boxed()
+}
+
+fn min_track(floats: Floats) -> BoxedStrategy<MinTrackSizingFunction> {
+    prop_oneof![
+        length_percentage(floats).prop_map(MinTrackSizingFunction::Fixed),
+        Just(MinTrackSizingFunction::MinContent),
+        Just(MinTrackSizingFunction::MaxContent),
+        Just(MinTrackSizingFunction::Auto),
+    ]
+    .boxed()
+}
+
+fn max_track(floats: Floats) -> BoxedStrategy<MaxTrackSizingFunction> {
+    prop_oneof![
+        length_percentage(floats).prop_map(MaxTrackSizingFunction::Fixed),
+        Just(MaxTrackSizingFunction::MinContent),
+        Just(MaxTrackSizingFunction::MaxContent),
+        length_percentage(floats).prop_map(MaxTrackSizingFunction::FitContent),
+        Just(MaxTrackSizingFunction::Auto),
+        float(floats).prop_map(MaxTrackSizingFunction::Fraction),
+    ]
+    .boxed()
+}
+
+fn track_size(floats: Floats) -> BoxedStrategy<NonRepeatedTrackSizingFunction> {
+    (min_track(floats), max_track(floats))
+        .prop_map(|(min, max)| NonRepeatedTrackSizingFunction { min, max })
+        .boxed()
+}
+
+// A valid repeat() repeats at least one track at least once.
+fn track(valid: bool, floats: Floats) -> BoxedStrategy<TrackSizingFunction> {
+    let repetition = prop_oneof![
+        Just(GridTrackRepetition::AutoFill),
+        Just(GridTrackRepetition::AutoFit),
+        (u16::from(valid)..=u16::MAX).prop_map(GridTrackRepetition::Count),
+    ];
+    prop_oneof![
+        track_size(floats).prop_map(TrackSizingFunction::Single),
+        (repetition, vec(track_size(floats), usize::from(valid)..4))
+            .prop_map(|(repetition, tracks)| TrackSizingFunction::Repeat(repetition, tracks)),
+    ]
+    .boxed()
+}
+
+fn grid_placement() -> BoxedStrategy<taffy_core::GridPlacement> {
+    prop_oneof![
+        any::<i16>()
+            .prop_filter("line 0 is invalid", |index| *index != 0)
+            .prop_map(taffy_core::GridPlacement::from_line_index),
+        (1..=u16::MAX).prop_map(taffy_core::GridPlacement::Span),
+        Just(taffy_core::GridPlacement::Auto),
+    ]
+    .boxed()
+}
+
+fn grid_line() -> BoxedStrategy<Line<taffy_core::GridPlacement>> {
+    (grid_placement(), grid_placement())
+        .prop_map(|(start, end)| Line { start, end })
+        .boxed()
+}
+
+prop_compose! {
+    fn box_model(valid: bool)(
+        display in display(),
+        overflow in (overflow(), overflow()),
+        scrollbar_width in float(floats(valid, Floats::NonNegative)),
+        position in position(),
+        inset in rect(length_percentage_auto(floats(valid, Floats::Finite))),
+        size in size(dimension(floats(valid, Floats::NonNegative))),
+        min_size in size(dimension(floats(valid, Floats::NonNegative))),
+        max_size in size(dimension(floats(valid, Floats::NonNegative))),
+        aspect_ratio in option::of(float(floats(valid, Floats::Positive))),
+        margin in rect(length_percentage_auto(floats(valid, Floats::Finite))),
+        padding in rect(length_percentage(floats(valid, Floats::NonNegative))),
+        border in rect(length_percentage(floats(valid, Floats::NonNegative))),
+    ) -> taffy_core::Style {
+        taffy_core::Style {
+            display,
+            overflow: Point { x: overflow.0, y: overflow.1 },
+            scrollbar_width,
+            position,
+            inset,
+            size,
+            min_size,
+            max_size,
+            aspect_ratio,
+            margin,
+            padding,
+            border,
+            ..Default::default()
+        }
+    }
+}
+
+prop_compose! {
+    fn any_style(valid: bool)(
+        style in box_model(valid),
+        align_items in option::of(align_items()),
+        align_self in option::of(align_items()),
+        justify_items in option::of(align_items()),
+        justify_self in option::of(align_items()),
+        align_content in option::of(align_content()),
+        justify_content in option::of(align_content()),
+        gap in size(length_percentage(floats(valid, Floats::NonNegative))),
+        flex in (
+            flex_direction(),
+            flex_wrap(),
+            dimension(floats(valid, Floats::NonNegative)),
+            float(floats(valid, Floats::NonNegative)),
+            float(floats(valid, Floats::NonNegative)),
+        ),
+        grid_template_rows in vec(track(valid, floats(valid, Floats::NonNegative)), 0..4),
+        grid_template_columns in vec(track(valid, floats(valid, Floats::NonNegative)), 0..4),
+        grid_auto in (
+            vec(track_size(floats(valid, Floats::NonNegative)), 0..4),
+            vec(track_size(floats(valid, Floats::NonNegative)), 0..4),
+            grid_auto_flow(),
+        ),
+        grid_row in grid_line(),
+        grid_column in grid_line(),
+    ) -> taffy_core::Style {
+        taffy_core::Style {
+            align_items,
+            align_self,
+            justify_items,
+            justify_self,
+            align_content,
+            justify_content,
+            gap,
+            flex_direction: flex.0,
+            flex_wrap: flex.1,
+            flex_basis: flex.2,
+            flex_grow: flex.3,
+            flex_shrink: flex.4,
+            grid_template_rows,
+            grid_template_columns,
+            grid_auto_rows: grid_auto.0,
+            grid_auto_columns: grid_auto.1,
+            grid_auto_flow: grid_auto.2,
+            grid_row,
+            grid_column,
+            ..style
+        }
+    }
+}
+
+proptest! {
+    #[test]
+    fn getters_and_setters_round_trip(style in any_style(true)) {
+        let copy = common::copy_style(&Style::from(style.clone()));
+        assert_same(&taffy_core::Style::try_from(&copy).unwrap(), &style);
+    }
+
+    #[test]
+    fn css_round_trips(style in any_style(true)) {
+        let css = Style::from(style.clone()).to_css().unwrap();
+        let copy = Style::from_css(css).unwrap();
+        // Declarations equal to the default are left out, so -0.0 comes back as
+        // 0.0 where it's the default.
+        assert_eq!(taffy_core::Style::try_from(&copy).unwrap(), style);
+    }
+
+    #[test]
+    fn style_data_round_trips(style in any_style(true)) {
+        let data = Style::from(style.clone()).to_data().unwrap();
+        let copy = Style::from_data(data).unwrap();
+        assert_same(&taffy_core::Style::try_from(&copy).unwrap(), &style);
+    }
+
+    #[test]
+    fn style_data_is_validated(style in any_style(false)) {
+        let data = || Style::from(style.clone()).to_data().unwrap();
+        match Style::from_data(data()) {
+            Ok(copy) => assert_same(&taffy_core::Style::try_from(&copy).unwrap(), &style),
+            Err(error) => prop_assert!(
+                matches!(error, TaffyError::InvalidStyleValue { .. }),
+                "{error}"
+            ),
+        }
+
+        let tree = TaffyTree::new();
+        tree.set_style_validation(StyleValidation::Lenient).unwrap();
+        let node = tree.new_leaf(Arc::new(Style::new())).unwrap();
+        tree.set_style_data(node, data()).unwrap();
+        Style::from_data(tree.get_style_data(node).unwrap()).unwrap();
+    }
+}
+
+fn layout_second_grid_item(placement: LineGridPlacement) -> taffy::Layout {
+    let style = Style::new();
+    style.set_grid_column(placement).unwrap();
+    let tree = TaffyTree::new();
+    let grid = Style::from_css("display: grid; grid-template-columns: 10px 20px".into()).unwrap();
+    let first = tree.new_leaf(Arc::new(Style::new())).unwrap();
+    let second = tree.new_leaf(Arc::new(style)).unwrap();
+    let root = tree
+        .new_with_children(Arc::new(grid), vec![first, second])
+        .unwrap();
+    let available_space = SizeAvailableSpace {
+        width: AvailableSpace::MaxContent,
+        height: AvailableSpace::MaxContent,
+    };
+    tree.compute_layout(root, available_space).unwrap();
+    tree.get_layout(second).unwrap()
+}
+
+#[test]
+fn grid_line_zero_becomes_auto() {
+    let placement = LineGridPlacement {
+        start: GridPlacement::Line { index: 0 },
+        end: GridPlacement::Line { index: 0 },
+    };
+    let style = Style::new();
+    style.set_grid_column(placement).unwrap();
+    let placement = style.get_grid_column().unwrap();
+    assert!(matches!(placement.start, GridPlacement::Auto));
+    assert!(matches!(placement.end, GridPlacement::Auto));
+
+    let layout = layout_second_grid_item(LineGridPlacement {
+        start: GridPlacement::Line { index: 0 },
+        end: GridPlacement::Auto,
+    });
+    assert_eq!(layout.location.x, 10.0);
+}
+
+#[test]
+fn span_zero_becomes_auto() {
+    let placement = LineGridPlacement {
+        start: GridPlacement::Span { span: 0 },
+        end: GridPlacement::Auto,
+    };
+    let style = Style::new();
+    style.set_grid_column(placement).unwrap();
+    assert!(matches!(
+        style.get_grid_column().unwrap().start,
+        GridPlacement::Auto
+    ));
+
+    let layout = layout_second_grid_item(LineGridPlacement {
+        start: GridPlacement::Span { span: 0 },
+        end: GridPlacement::Auto,
+    });
+    assert_eq!(layout.location.x, 10.0);
+}
+
+#[test]
+fn nan_keeps_its_bits() {
+    let nan = f32::from_bits(0x7fc0_1234);
+    let style = Style::from(taffy_core::Style {
+        flex_grow: nan,
+        ..Default::default()
+    });
+    assert_eq!(style.get_flex_grow().unwrap().to_bits(), nan.to_bits());
+    assert_eq!(style.to_data().unwrap().flex_grow.to_bits(), nan.to_bits());
+}
@@ -0,0 +1,157 @@
+use taffy::{error_message, Dimension, Style, TaffyError};
+
+fn css_error(css: &str) -> TaffyError {
+    match Style::from_css(css.into()) {
+        Err(error) => error,
+        Ok(style) => panic!("expected {css:?} to be invalid, got {:?}", style.to_css()),
+    }
+}
+
+#[test]
+fn errors_carry_their_position() {
+    let error = css_error("display: flex;\n  width: 10qq");
+    match &error {
+        TaffyError::InvalidCss {
+            line,
+            column,
+            reason,
+        } => {
+            assert_eq!((*line, *column), (2, 10));
+            assert_eq!(reason, "unsupported unit 'qq'");
+        }
+        error => panic!("unexpected error {error}"),
+    }
+    assert_eq!(
+        error_message(error),
+        "invalid CSS at 2:10: unsupported unit 'qq'"
+    );
+}
+
+fn assert_css_error(css: &str, expected_line: u32, expected_column: u32, expected_reason: &str) {
+    match css_error(css) {
+        TaffyError::InvalidCss {
+            line,
+            column,
+            reason,
+        } => {
+            assert_eq!((line, column), (expected_line, expected_column), "{css:?}");
+            assert_eq!(reason, expected_reason, "{css:?}");
+        }
+        error => panic!("unexpected error {error} for {css:?}"),
+    }
+}
+
+#[test]
+fn errors_on_later_lines() {
+    assert_css_error(
+        "display: flex;\nwidth: 10px;\n  height: auto;\n  flex-grow: -1",
+        4,
+        14,
+        "expected a non-negative number",
+    );
+    assert_css_error(
+        "/* a comment\n   over two lines */ display: sideways",
+        2,
+        31,
+        "expected one of block, flex, grid, none",
+    );
+    // Columns count characters, not bytes.
+    assert_css_error("/* é */ width: 1qq", 1, 16, "unsupported unit 'qq'");
+}
+
+#[test]
+fn errors_in_the_middle_of_a_declaration() {
+    assert_css_error(
+        "margin: 1px 2px oops 4px",
+        1,
+        17,
+        "expected a length or percentage",
+    );
+    assert_css_error(
+        "padding: 1px 2px 3px 4px 5px; width: 1px",
+        1,
+        26,
+        "unexpected trailing value",
+    );
+    assert_css_error(
+        "grid-template-columns: 10px repeat(0, 1fr)",
+        1,
+        36,
+        "repeat count must be a positive integer",
+    );
+    assert_css_error("width 10px", 1, 7, "expected ':'");
+}
+
+#[test]
+fn errors_on_unknown_properties() {
+    assert_css_error(
+        "display: flex;\n  colour: red",
+        2,
+        3,
+        "unsupported property 'colour'",
+    );
+    assert_css_error("display: flex; 10px", 1, 16, "expected a property name");
+    assert_css_error("width: 10px @", 1, 13, "unexpected character '@'");
+}
+
+#[test]
+fn errors_on_unterminated_input() {
+    assert_css_error(
+        "width: 10px;\n/* never closed",
+        2,
+        1,
+        "unterminated comment",
+    );
+    assert_css_error("display", 1, 8, "expected ':'");
+    assert_css_error("display: flex;\nwidth:", 2, 7, "missing value for 'width'");
+    assert_css_error("grid-template-rows: repeat(2, 10px", 1, 35, "expected ')'");
+}
+
+fn percent_width(value: f32) -> String {
+    let mut data = Style::new().to_data().unwrap();
+    data.size.width = Dimension::Percent { value };
+    Style::from_data(data).unwrap().to_css().unwrap()
+}
+
+#[test]
+fn percentages_round_trip_exactly() {
+    assert_eq!(percent_width(0.1), "width: 10%;");
+    assert_eq!(percent_width(0.5), "width: 50%;");
+    assert_eq!(percent_width(1.0 / 3.0), "width: 33.333334%;");
+    for value in [0.1, 0.07, 1.0 / 3.0, 0.123_456_79, 1e-7, 3e30, 0.29] {
+        let copy = Style::from_css(percent_width(value)).unwrap();
+        match copy.get_size().unwrap().width {
+            Dimension::Percent { value: copied } => assert_eq!(copied, value),
+            _ => panic!("expected a percentage for {value}"),
+        }
+    }
+}
+
+#[test]
+fn non_finite_values_have_no_css() {
+    // Styles are validated when they're set, so only a taffy style can still
+    // hold one.
+    let style = Style::from(taffy_core::Style {
+        flex_grow: f32::NAN,
+        ..Default::default()
+    });
+    match style.to_css() {
+        Err(TaffyError::InvalidStyleValue { property, value }) => {
+            assert_eq!(property, "flex-grow");
+            assert!(value.is_nan());
+        }
+        result => panic!("unexpected result {result:?}"),
+    }
+
+    let style = Style::from(taffy_core::Style {
+        margin: taffy_core::Rect {
+            top: taffy_core::LengthPercentageAuto::Percent(f32::INFINITY),
+            ..taffy_core::Style::DEFAULT.margin
+        },
+        ..Default::default()
+    });
+    assert!(matches!(
+        style.to_css(),
+        Err(TaffyError::InvalidStyleValue { property, .. }) if property == "margin"
+    ));
+}
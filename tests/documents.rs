@@ -0,0 +1,380 @@
+// Exporting trees to JSON documents and binary snapshots and importing them
+// again.
+
+use std::sync::Arc;
+
+use serde_json::{json, Value};
+use taffy::{
+    AvailableSpace, GridTrackRepetition, LengthPercentage, MaxTrackSizingFunction,
+    MinTrackSizingFunction, NodeContext, NodeId, NonRepeatedTrackSizingFunction,
+    SizeAvailableSpace, Style, StyleValidation, TaffyError, TaffyTree, TrackSizingFunction,
+};
+
+const MAX_CONTENT: SizeAvailableSpace = SizeAvailableSpace {
+    width: AvailableSpace::MaxContent,
+    height: AvailableSpace::MaxContent,
+};
+
+fn node(tree: &TaffyTree, css: &str, children: Vec<NodeId>) -> NodeId {
+    let style = Style::from_css(css.into()).unwrap();
+    tree.new_with_children(Arc::new(style), children).unwrap()
+}
+
+// A flex row holding a grid and a leaf, with contexts on the leaf and a cell.
+fn sample(tree: &TaffyTree) -> NodeId {
+    let cells: Vec<_> = (0..3).map(|_| node(tree, "height: 10px", vec![])).collect();
+    let grid = node(
+        tree,
+        "display: grid; grid-template-columns: 20px 1fr; gap: 5px 2.5%",
+        cells.clone(),
+    );
+    tree.set_node_context(
+        cells[2],
+        Some(NodeContext::Bytes {
+            value: b"cell".to_vec(),
+        }),
+    )
+    .unwrap();
+    let leaf = node(tree, "flex-grow: 1; margin: 3px auto 0", vec![]);
+    tree.set_node_context(leaf, Some(NodeContext::Handle { value: 7 }))
+        .unwrap();
+    node(
+        tree,
+        "display: flex; width: 200px; padding: 4px",
+        vec![grid, leaf],
+    )
+}
+
+// Layouts aren't PartialEq, so they're compared by their Debug output.
+fn layouts(tree: &TaffyTree, root: NodeId) -> Vec<String> {
+    tree.compute_layout(root, MAX_CONTENT).unwrap();
+    tree.get_layouts(root)
+        .unwrap()
+        .into_iter()
+        .map(|entry| format!("{:?}", entry.layout))
+        .collect()
+}
+
+fn assert_invalid(json: Value, expected: &str) {
+    let tree = TaffyTree::new();
+    match tree.from_json(json.to_string()) {
+        Err(TaffyError::InvalidDocument { reason }) => {
+            assert!(reason.contains(expected), "{reason:?} for {json}")
+        }
+        result => panic!("expected {json} to be invalid, got {result:?}"),
+    }
+    assert_eq!(tree.get_total_node_count().unwrap(), 0);
+}
+
+fn assert_invalid_binary(data: &[u8], expected: &str) {
+    let tree = TaffyTree::new();
+    match tree.import_binary(data.to_vec()) {
+        Err(TaffyError::InvalidDocument { reason }) => {
+            assert!(reason.contains(expected), "{reason:?} for {data:?}")
+        }
+        result => panic!("expected {data:?} to be invalid, got {result:?}"),
+    }
+    assert_eq!(tree.get_total_node_count().unwrap(), 0);
+}
+
+#[test]
+fn imported_trees_lay_out_the_same() {
+    let tree = TaffyTree::new();
+    let root = sample(&tree);
+    let json = tree.to_json(root, false).unwrap();
+
+    let copy = TaffyTree::new();
+    let copied_root = copy.from_json(json.clone()).unwrap();
+    assert_eq!(layouts(&copy, copied_root), layouts(&tree, root));
+    assert_eq!(copy.to_json(copied_root, false).unwrap(), json);
+
+    let leaf = copy.get_children(copied_root).unwrap()[1];
+    assert_eq!(
+        copy.get_node_context(leaf).unwrap(),
+        Some(NodeContext::Handle { value: 7 })
+    );
+}
+
+#[test]
+fn styles_are_written_as_css() {
+    let tree = TaffyTree::new();
+    let root = node(&tree, "display: grid; width: 10%", vec![]);
+    let json: Value = serde_json::from_str(&tree.to_json(root, false).unwrap()).unwrap();
+    assert_eq!(
+        json,
+        json!({
+            "version": 1,
+            "nodes": [{ "style": "display: grid;\nwidth: 10%;" }],
+        })
+    );
+}
+
+#[test]
+fn layouts_are_included_on_request() {
+    let tree = TaffyTree::new();
+    let leaf = node(&tree, "width: 30px; height: 20px", vec![]);
+    let root = node(&tree, "display: flex; padding: 5px", vec![leaf]);
+    tree.compute_layout(root, MAX_CONTENT).unwrap();
+
+    let json: Value = serde_json::from_str(&tree.to_json(root, true).unwrap()).unwrap();
+    assert_eq!(
+        json["nodes"][1]["layout"],
+        json!({
+            "order": 0,
+            "location": { "x": 5.0, "y": 5.0 },
+            "size": { "width": 30.0, "height": 20.0 },
+            "content_size": { "width": 0.0, "height": 0.0 },
+            "scrollbar_size": { "width": 0.0, "height": 0.0 },
+            "border": { "left": 0.0, "right": 0.0, "top": 0.0, "bottom": 0.0 },
+            "padding": { "left": 0.0, "right": 0.0, "top": 0.0, "bottom": 0.0 },
+        })
+    );
+    assert_eq!(json["nodes"][0]["layout"]["size"]["width"], 40.0);
+
+    let json: Value = serde_json::from_str(&tree.to_json(root, false).unwrap()).unwrap();
+    assert!(json["nodes"][0].get("layout").is_none());
+
+    // Layouts are recomputed on import rather than restored.
+    let copy = TaffyTree::new();
+    let copied_root = copy.from_json(json.to_string()).unwrap();
+    assert!(copy.is_dirty(copied_root).unwrap());
+}
+
+#[test]
+fn malformed_documents_are_rejected() {
+    let tree = TaffyTree::new();
+    for json in ["", "{", "[]", "not json"] {
+        assert!(matches!(
+            tree.from_json(json.into()),
+            Err(TaffyError::InvalidDocument { .. })
+        ));
+    }
+
+    assert_invalid(json!({ "version": 1 }), "missing field `nodes`");
+    assert_invalid(
+        json!({ "version": 1, "nodes": [] }),
+        "document has no nodes",
+    );
+    assert_invalid(
+        json!({ "version": 2, "nodes": [{ "style": "" }] }),
+        "unsupported version 2",
+    );
+    assert_invalid(
+        json!({ "version": 1, "nodes": [{ "style": { "display": "Flex" } }] }),
+        "expected a string",
+    );
+    assert_invalid(
+        json!({ "version": 1, "nodes": [{ "style": "display: sideways" }] }),
+        "invalid CSS at 1:10",
+    );
+    assert_invalid(
+        json!({ "version": 1, "nodes": [{ "style": "flex-grow: -1" }] }),
+        "invalid CSS",
+    );
+    assert_invalid(
+        json!({ "version": 1, "nodes": [{ "style": "", "children": [1] }] }),
+        "node 0 has invalid child 1",
+    );
+    assert_invalid(
+        json!({ "version": 1, "nodes": [{ "style": "", "children": [0] }] }),
+        "node 0 has invalid child 0",
+    );
+    assert_invalid(
+        json!({
+            "version": 1,
+            "nodes": [
+                { "style": "", "children": [1, 2] },
+                { "style": "", "children": [2] },
+                { "style": "" },
+            ],
+        }),
+        "node 2 has more than one parent",
+    );
+    assert_invalid(
+        json!({ "version": 1, "nodes": [{ "style": "" }, { "style": "" }] }),
+        "node 1 is not reachable from the root",
+    );
+}
+
+fn ten_pixels() -> NonRepeatedTrackSizingFunction {
+    let value = || LengthPercentage::Length { value: 10.0 };
+    NonRepeatedTrackSizingFunction {
+        min: MinTrackSizingFunction::Fixed { value: value() },
+        max: MaxTrackSizingFunction::Fixed { value: value() },
+    }
+}
+
+// CSS can't write a repeat() of zero times or of no tracks, so trees can't hold
+// one and every tree that's written can be read again.
+#[test]
+fn repeats_without_tracks_are_never_written() {
+    let tracks = || {
+        vec![
+            TrackSizingFunction::Repeat {
+                rep: GridTrackRepetition::Count { value: 0 },
+                funcs: vec![ten_pixels()],
+            },
+            TrackSizingFunction::Repeat {
+                rep: GridTrackRepetition::Count { value: 2 },
+                funcs: vec![],
+            },
+            TrackSizingFunction::Single { func: ten_pixels() },
+        ]
+    };
+
+    let strict = TaffyTree::new();
+    let root = node(&strict, "display: grid", vec![]);
+    let mut data = strict.get_style_data(root).unwrap();
+    data.grid_template_columns = tracks();
+    assert!(matches!(
+        strict.set_style_data(root, data),
+        Err(TaffyError::InvalidStyleValue { property, .. }) if property == "grid-template-columns"
+    ));
+    assert!(strict
+        .get_style(root)
+        .unwrap()
+        .set_grid_template_columns(tracks())
+        .is_err());
+
+    let tree = TaffyTree::new();
+    tree.set_style_validation(StyleValidation::Lenient).unwrap();
+    let root = node(&tree, "display: grid", vec![]);
+    let mut data = tree.get_style_data(root).unwrap();
+    data.grid_template_columns = tracks();
+    tree.set_style_data(root, data).unwrap();
+    assert_eq!(
+        tree.get_style(root).unwrap().to_css().unwrap(),
+        "display: grid;\ngrid-template-columns: 10px;"
+    );
+
+    let json = tree.to_json(root, false).unwrap();
+    let copy = TaffyTree::new();
+    let copied_root = copy.from_json(json.clone()).unwrap();
+    assert_eq!(copy.to_json(copied_root, false).unwrap(), json);
+    let data = tree.export_binary(root).unwrap();
+    let copied_root = copy.import_binary(data.clone()).unwrap();
+    assert_eq!(copy.export_binary(copied_root).unwrap(), data);
+}
+
+// Snapshots of sample() written by each version of the format, the last one
+// being the current version.
+const SNAPSHOTS: [&[u8]; 2] = [
+    include_bytes!("snapshots/v1.bin"),
+    include_bytes!("snapshots/v2.bin"),
+];
+const CURRENT_SNAPSHOT: &[u8] = SNAPSHOTS[SNAPSHOTS.len() - 1];
+
+#[test]
+fn binary_snapshots_round_trip() {
+    let tree = TaffyTree::new();
+    let root = sample(&tree);
+    let data = tree.export_binary(root).unwrap();
+
+    let copy = TaffyTree::new();
+    let copied_root = copy.import_binary(data.clone()).unwrap();
+    assert_eq!(layouts(&copy, copied_root), layouts(&tree, root));
+    assert_eq!(copy.export_binary(copied_root).unwrap(), data);
+    assert_eq!(
+        copy.to_json(copied_root, false).unwrap(),
+        tree.to_json(root, false).unwrap()
+    );
+}
+
+#[test]
+fn grid_snapshots_round_trip() {
+    let tree = TaffyTree::new();
+    let items = [
+        "grid-row: 1 / span 2; grid-column: -1",
+        "grid-row: span 3; grid-column: 2 / span 2",
+        "grid-column: span 2; width: 25%; align-self: end",
+        "grid-row: -3 / span 2; justify-self: center; aspect-ratio: 2",
+        "",
+    ]
+    .iter()
+    .map(|css| node(&tree, css, vec![]))
+    .collect();
+    let root = node(
+        &tree,
+        "display: grid; width: 300px; \
+         grid-template-columns: 40px repeat(auto-fill, minmax(min-content, 20px) 10%) 1fr; \
+         grid-template-rows: repeat(2, fit-content(30px) min-content) 2fr max-content; \
+         grid-auto-rows: minmax(auto, 50px) 3fr; grid-auto-columns: 15px; \
+         grid-auto-flow: row dense; gap: 4px 2%; justify-items: stretch; \
+         align-content: space-evenly",
+        items,
+    );
+    let data = tree.export_binary(root).unwrap();
+
+    let copy = TaffyTree::new();
+    let copied_root = copy.import_binary(data.clone()).unwrap();
+    assert_eq!(layouts(&copy, copied_root), layouts(&tree, root));
+    assert_eq!(copy.export_binary(copied_root).unwrap(), data);
+    assert_eq!(
+        copy.to_json(copied_root, false).unwrap(),
+        tree.to_json(root, false).unwrap()
+    );
+}
+
+#[test]
+fn snapshots_of_every_version_keep_loading() {
+    let expected = TaffyTree::new();
+    let expected_root = sample(&expected);
+    for (index, snapshot) in SNAPSHOTS.iter().enumerate() {
+        let tree = TaffyTree::new();
+        let root = tree.import_binary(snapshot.to_vec()).unwrap();
+        assert_eq!(
+            layouts(&tree, root),
+            layouts(&expected, expected_root),
+            "version {}",
+            index + 1
+        );
+        assert_eq!(
+            tree.to_json(root, false).unwrap(),
+            expected.to_json(expected_root, false).unwrap()
+        );
+
+        // A change to what gets written needs a new version, along with a
+        // new fixture for it next to these.
+        assert_eq!(tree.export_binary(root).unwrap(), CURRENT_SNAPSHOT);
+    }
+}
+
+#[test]
+fn truncated_snapshots_are_rejected() {
+    assert_invalid_binary(&[], "not a binary tree snapshot");
+    for snapshot in SNAPSHOTS {
+        assert_invalid_binary(&snapshot[..6], "truncated binary tree snapshot");
+        for length in 8..snapshot.len() {
+            let tree = TaffyTree::new();
+            assert!(
+                matches!(
+                    tree.import_binary(snapshot[..length].to_vec()),
+                    Err(TaffyError::InvalidDocument { .. })
+                ),
+                "{length} bytes"
+            );
+            assert_eq!(tree.get_total_node_count().unwrap(), 0);
+        }
+    }
+}
+
+#[test]
+fn snapshots_need_the_magic_bytes() {
+    let mut data = CURRENT_SNAPSHOT.to_vec();
+    data[3] = b'X';
+    assert_invalid_binary(&data, "not a binary tree snapshot");
+
+    let tree = TaffyTree::new();
+    let root = sample(&tree);
+    let json = tree.to_json(root, false).unwrap();
+    assert_invalid_binary(json.as_bytes(), "not a binary tree snapshot");
+}
+
+#[test]
+fn newer_snapshots_are_rejected() {
+    let mut data = CURRENT_SNAPSHOT.to_vec();
+    data[4..8].copy_from_slice(&3u32.to_le_bytes());
+    assert_invalid_binary(&data, "unsupported version 3");
+    data[4..8].copy_from_slice(&0u32.to_le_bytes());
+    assert_invalid_binary(&data, "unsupported version 0");
+}
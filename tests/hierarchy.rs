@@ -0,0 +1,116 @@
+// Hierarchy changes that taffy itself would panic on or get wrong. The fuzz
+// target in fuzz/ covers arbitrary sequences of them.
+
+use std::sync::Arc;
+
+use taffy::{AvailableSpace, NodeId, SizeAvailableSpace, Style, TaffyError, TaffyTree};
+
+const MAX_CONTENT: SizeAvailableSpace = SizeAvailableSpace {
+    width: AvailableSpace::MaxContent,
+    height: AvailableSpace::MaxContent,
+};
+
+fn leaf(tree: &TaffyTree) -> NodeId {
+    tree.new_leaf(Arc::new(Style::new())).unwrap()
+}
+
+#[test]
+fn removed_nodes_are_rejected() {
+    let tree = TaffyTree::new();
+    let parent = leaf(&tree);
+    let child = leaf(&tree);
+    tree.remove(child).unwrap();
+
+    assert!(matches!(
+        tree.get_layout(child),
+        Err(TaffyError::InvalidInputNode { .. })
+    ));
+    assert!(matches!(
+        tree.insert_child_at_index(parent, 0, child),
+        Err(TaffyError::InvalidChildNode { .. })
+    ));
+    assert!(matches!(
+        tree.set_children(child, vec![parent]),
+        Err(TaffyError::InvalidParentNode { .. })
+    ));
+    assert!(matches!(
+        tree.compute_layout(NodeId::from(u64::MAX), MAX_CONTENT),
+        Err(TaffyError::InvalidInputNode { .. })
+    ));
+}
+
+#[test]
+fn attaching_a_child_moves_it() {
+    let tree = TaffyTree::new();
+    let first = leaf(&tree);
+    let second = leaf(&tree);
+    let child = leaf(&tree);
+    tree.set_children(first, vec![child]).unwrap();
+
+    tree.insert_child_at_index(second, 0, child).unwrap();
+    assert_eq!(tree.get_children(first).unwrap(), vec![]);
+    assert_eq!(tree.get_parent(child).unwrap(), Some(second));
+
+    let parent = tree
+        .new_with_children(Arc::new(Style::new()), vec![child])
+        .unwrap();
+    assert_eq!(tree.get_children(second).unwrap(), vec![]);
+    assert_eq!(tree.get_parent(child).unwrap(), Some(parent));
+}
+
+#[test]
+fn moving_a_child_within_its_parent() {
+    let tree = TaffyTree::new();
+    let children: Vec<NodeId> = (0..3).map(|_| leaf(&tree)).collect();
+    let parent = tree
+        .new_with_children(Arc::new(Style::new()), children.clone())
+        .unwrap();
+
+    tree.insert_child_at_index(parent, 2, children[0]).unwrap();
+    assert_eq!(
+        tree.get_children(parent).unwrap(),
+        vec![children[1], children[2], children[0]]
+    );
+
+    let replaced = tree.replace_child_at_index(parent, 1, children[1]).unwrap();
+    assert_eq!(replaced, children[2]);
+    assert_eq!(
+        tree.get_children(parent).unwrap(),
+        vec![children[1], children[0]]
+    );
+    assert_eq!(tree.get_parent(children[2]).unwrap(), None);
+}
+
+#[test]
+fn cycles_are_rejected() {
+    let tree = TaffyTree::new();
+    let child = leaf(&tree);
+    let parent = tree
+        .new_with_children(Arc::new(Style::new()), vec![child])
+        .unwrap();
+
+    assert!(matches!(
+        tree.insert_child_at_index(child, 0, parent),
+        Err(TaffyError::CyclicChild { .. })
+    ));
+    assert!(matches!(
+        tree.set_children(parent, vec![parent]),
+        Err(TaffyError::CyclicChild { .. })
+    ));
+    assert!(matches!(
+        tree.set_children(parent, vec![child, child]),
+        Err(TaffyError::DuplicateChild { .. })
+    ));
+    tree.compute_layout(parent, MAX_CONTENT).unwrap();
+}
+
+#[test]
+fn removing_a_node_that_is_not_a_child() {
+    let tree = TaffyTree::new();
+    let parent = leaf(&tree);
+    let other = leaf(&tree);
+    assert!(matches!(
+        tree.remove_child(parent, other),
+        Err(TaffyError::NotAChild { .. })
+    ));
+}
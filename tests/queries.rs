@@ -0,0 +1,476 @@
+// Absolute layouts, scroll offsets, hit testing and the debug tree.
+
+use std::sync::Arc;
+
+use taffy::{
+    AvailableSpace, Dimension, Display, NodeContext, NodeId, PointFloat, RectFloat,
+    SizeAvailableSpace, Style, TaffyError, TaffyTree,
+};
+
+const MAX_CONTENT: SizeAvailableSpace = SizeAvailableSpace {
+    width: AvailableSpace::MaxContent,
+    height: AvailableSpace::MaxContent,
+};
+
+fn node(tree: &TaffyTree, css: &str, children: Vec<NodeId>) -> NodeId {
+    let style = Style::from_css(css.into()).unwrap();
+    tree.new_with_children(Arc::new(style), children).unwrap()
+}
+
+fn point(x: f32, y: f32) -> PointFloat {
+    PointFloat { x, y }
+}
+
+// A scroll container at (10, 10) holding a tall box at (5, 5) within it,
+// which holds a leaf at (7, 7).
+struct Scroller {
+    tree: TaffyTree,
+    root: NodeId,
+    scroller: NodeId,
+    content: NodeId,
+    leaf: NodeId,
+}
+
+fn scroller() -> Scroller {
+    let tree = TaffyTree::new();
+    let leaf = node(&tree, "width: 10px; height: 10px", vec![]);
+    let content = node(
+        &tree,
+        "flex-shrink: 0; width: 50px; height: 300px; padding: 7px",
+        vec![leaf],
+    );
+    let scroller = node(
+        &tree,
+        "display: flex; flex-direction: column; overflow: scroll; scrollbar-width: 0;
+         width: 100px; height: 100px; padding: 5px",
+        vec![content],
+    );
+    let root = node(
+        &tree,
+        "display: flex; flex-direction: column; padding: 10px",
+        vec![scroller],
+    );
+    tree.compute_layout(root, MAX_CONTENT).unwrap();
+    Scroller {
+        tree,
+        root,
+        scroller,
+        content,
+        leaf,
+    }
+}
+
+fn absolute_location(tree: &TaffyTree, node: NodeId) -> PointFloat {
+    let location = tree.get_absolute_layout(node).unwrap().location;
+    let from_root = tree
+        .get_absolute_layouts(tree_root(tree, node))
+        .unwrap()
+        .into_iter()
+        .find(|entry| entry.node == node)
+        .unwrap()
+        .layout
+        .location;
+    assert_eq!(location, from_root);
+    location
+}
+
+fn tree_root(tree: &TaffyTree, mut node: NodeId) -> NodeId {
+    while let Some(parent) = tree.get_parent(node).unwrap() {
+        node = parent;
+    }
+    node
+}
+
+#[test]
+fn absolute_layouts_add_up_locations() {
+    let Scroller {
+        tree,
+        root,
+        scroller,
+        content,
+        leaf,
+    } = scroller();
+
+    assert_eq!(absolute_location(&tree, root), point(0.0, 0.0));
+    assert_eq!(absolute_location(&tree, scroller), point(10.0, 10.0));
+    assert_eq!(absolute_location(&tree, content), point(15.0, 15.0));
+    assert_eq!(absolute_location(&tree, leaf), point(22.0, 22.0));
+
+    let layouts = tree.get_absolute_layouts(root).unwrap();
+    let nodes: Vec<NodeId> = layouts.iter().map(|entry| entry.node).collect();
+    assert_eq!(nodes, [root, scroller, content, leaf]);
+    assert_eq!(layouts[3].layout.size, tree.get_layout(leaf).unwrap().size);
+}
+
+#[test]
+fn absolute_layouts_subtract_ancestor_scroll_offsets() {
+    let Scroller {
+        tree,
+        scroller,
+        content,
+        leaf,
+        ..
+    } = scroller();
+
+    tree.set_scroll_offset(scroller, point(3.0, 40.0)).unwrap();
+    assert_eq!(tree.get_scroll_offset(scroller).unwrap(), point(3.0, 40.0));
+    // A node's own offset moves its content, not the node itself.
+    assert_eq!(absolute_location(&tree, scroller), point(10.0, 10.0));
+    assert_eq!(absolute_location(&tree, content), point(12.0, -25.0));
+    assert_eq!(absolute_location(&tree, leaf), point(19.0, -18.0));
+
+    tree.set_scroll_offset(content, point(0.0, 2.0)).unwrap();
+    assert_eq!(absolute_location(&tree, content), point(12.0, -25.0));
+    assert_eq!(absolute_location(&tree, leaf), point(19.0, -20.0));
+
+    tree.set_scroll_offset(scroller, point(0.0, 0.0)).unwrap();
+    assert_eq!(absolute_location(&tree, leaf), point(22.0, 20.0));
+    // Relative layouts never include scrolling.
+    assert_eq!(tree.get_layout(leaf).unwrap().location, point(7.0, 7.0));
+}
+
+#[test]
+fn scrolling_does_not_invalidate_layouts() {
+    let Scroller {
+        tree,
+        root,
+        scroller,
+        content,
+        leaf,
+    } = scroller();
+    // A node that already carries data, which scrolling must not replace.
+    tree.set_node_context(content, Some(NodeContext::Handle { value: 7 }))
+        .unwrap();
+    tree.compute_layout(root, MAX_CONTENT).unwrap();
+
+    tree.set_scroll_offset(scroller, point(0.0, 25.0)).unwrap();
+    tree.set_scroll_offset(content, point(1.0, 0.0)).unwrap();
+    for node in [root, scroller, content, leaf] {
+        assert!(!tree.is_dirty(node).unwrap());
+    }
+    assert_eq!(
+        tree.get_node_context(content).unwrap(),
+        Some(NodeContext::Handle { value: 7 })
+    );
+
+    // Offsets outlive a relayout.
+    tree.mark_dirty(leaf).unwrap();
+    tree.compute_layout(root, MAX_CONTENT).unwrap();
+    assert_eq!(tree.get_scroll_offset(scroller).unwrap(), point(0.0, 25.0));
+}
+
+#[test]
+fn scroll_offsets_go_with_their_node() {
+    let tree = TaffyTree::new();
+    let leaf = node(&tree, "", vec![]);
+    assert_eq!(tree.get_scroll_offset(leaf).unwrap(), point(0.0, 0.0));
+    tree.set_scroll_offset(leaf, point(4.0, 5.0)).unwrap();
+
+    tree.remove(leaf).unwrap();
+    assert!(matches!(
+        tree.set_scroll_offset(leaf, point(1.0, 1.0)),
+        Err(TaffyError::InvalidInputNode { .. })
+    ));
+    assert!(matches!(
+        tree.get_scroll_offset(leaf),
+        Err(TaffyError::InvalidInputNode { .. })
+    ));
+
+    // A new node starts out unscrolled.
+    let other = node(&tree, "", vec![]);
+    assert_eq!(tree.get_scroll_offset(other).unwrap(), point(0.0, 0.0));
+}
+
+fn rect(left: f32, top: f32, right: f32, bottom: f32) -> RectFloat {
+    RectFloat {
+        left,
+        right,
+        top,
+        bottom,
+    }
+}
+
+// Two overlapping boxes, the second holding a small one in its corner, and a
+// hidden box over all of them.
+struct Stack {
+    tree: TaffyTree,
+    root: NodeId,
+    first: NodeId,
+    second: NodeId,
+    inner: NodeId,
+}
+
+fn stack() -> Stack {
+    let tree = TaffyTree::new();
+    let absolute =
+        |css: &str, children| node(&tree, &format!("position: absolute; {css}"), children);
+    let first = absolute("left: 0; top: 0; width: 50px; height: 50px", vec![]);
+    let inner = absolute("left: 0; top: 0; width: 10px; height: 10px", vec![]);
+    let second = absolute(
+        "left: 25px; top: 25px; width: 50px; height: 50px",
+        vec![inner],
+    );
+    let covered = absolute("left: 0; top: 0; width: 10px; height: 10px", vec![]);
+    let hidden = absolute(
+        "display: none; left: 0; top: 0; width: 100px; height: 100px",
+        vec![covered],
+    );
+    let root = node(
+        &tree,
+        "width: 100px; height: 100px",
+        vec![first, second, hidden],
+    );
+    tree.compute_layout(root, MAX_CONTENT).unwrap();
+    Stack {
+        tree,
+        root,
+        first,
+        second,
+        inner,
+    }
+}
+
+#[test]
+fn hits_are_front_to_back() {
+    let Stack {
+        tree,
+        root,
+        first,
+        second,
+        inner,
+    } = stack();
+
+    // Later siblings are in front of earlier ones, and children in front of
+    // their parent. The hidden box and its child are never hit.
+    assert_eq!(
+        tree.hit_test(root, point(30.0, 30.0)).unwrap(),
+        [inner, second, first, root]
+    );
+    assert_eq!(
+        tree.hit_test(root, point(10.0, 10.0)).unwrap(),
+        [first, root]
+    );
+    // Boxes include their top left edges and exclude their bottom right ones.
+    assert_eq!(
+        tree.hit_test(root, point(50.0, 50.0)).unwrap(),
+        [second, root]
+    );
+    assert_eq!(tree.hit_test(root, point(0.0, 0.0)).unwrap(), [first, root]);
+    assert_eq!(tree.hit_test(root, point(100.0, 10.0)).unwrap(), []);
+    // Only the subtree of the given node is searched.
+    assert_eq!(
+        tree.hit_test(second, point(30.0, 30.0)).unwrap(),
+        [inner, second]
+    );
+}
+
+#[test]
+fn rectangles_hit_partial_overlaps() {
+    let Stack {
+        tree,
+        root,
+        first,
+        second,
+        inner,
+    } = stack();
+
+    assert_eq!(
+        tree.hit_test_rect(root, rect(45.0, 5.0, 55.0, 15.0))
+            .unwrap(),
+        [first, root]
+    );
+    assert_eq!(
+        tree.hit_test_rect(root, rect(30.0, 30.0, 40.0, 40.0))
+            .unwrap(),
+        [inner, second, first, root]
+    );
+    // Touching an edge isn't overlapping.
+    assert_eq!(
+        tree.hit_test_rect(root, rect(50.0, 0.0, 60.0, 10.0))
+            .unwrap(),
+        [root]
+    );
+    assert_eq!(
+        tree.hit_test_rect(root, rect(100.0, 0.0, 200.0, 200.0))
+            .unwrap(),
+        []
+    );
+}
+
+#[test]
+fn overflow_clips_hits_on_children() {
+    let tree = TaffyTree::new();
+    let child = node(
+        &tree,
+        "position: absolute; left: 30px; top: 30px; width: 50px; height: 50px",
+        vec![],
+    );
+    let clipper_css = "position: absolute; left: 10px; top: 10px; width: 50px; height: 50px;
+                       border-width: 5px";
+    let clipper = node(
+        &tree,
+        &format!("{clipper_css}; overflow: hidden"),
+        vec![child],
+    );
+    let root = node(&tree, "width: 200px; height: 200px", vec![clipper]);
+    tree.compute_layout(root, MAX_CONTENT).unwrap();
+
+    // The child spans 45 to 95, but only shows inside the clipper's border,
+    // which ends at 55.
+    assert_eq!(
+        tree.hit_test(root, point(50.0, 50.0)).unwrap(),
+        [child, clipper, root]
+    );
+    assert_eq!(
+        tree.hit_test(root, point(57.0, 57.0)).unwrap(),
+        [clipper, root]
+    );
+    assert_eq!(tree.hit_test(root, point(70.0, 70.0)).unwrap(), [root]);
+    assert_eq!(
+        tree.hit_test_rect(root, rect(56.0, 56.0, 90.0, 90.0))
+            .unwrap(),
+        [clipper, root]
+    );
+
+    let visible = Style::from_css(clipper_css.into()).unwrap();
+    tree.set_style(clipper, Arc::new(visible)).unwrap();
+    tree.compute_layout(root, MAX_CONTENT).unwrap();
+    assert_eq!(
+        tree.hit_test(root, point(70.0, 70.0)).unwrap(),
+        [child, root]
+    );
+}
+
+#[test]
+fn hits_follow_scroll_offsets() {
+    let Scroller {
+        tree,
+        root,
+        scroller,
+        content,
+        leaf,
+    } = scroller();
+
+    assert_eq!(
+        tree.hit_test(root, point(25.0, 25.0)).unwrap(),
+        [leaf, content, scroller, root]
+    );
+
+    tree.set_scroll_offset(scroller, point(0.0, 40.0)).unwrap();
+    // The leaf moved up out of the scroll container, which clips it.
+    assert_eq!(
+        tree.hit_test(root, point(25.0, 25.0)).unwrap(),
+        [content, scroller, root]
+    );
+    assert_eq!(
+        tree.hit_test_rect(root, rect(0.0, -20.0, 40.0, 9.0))
+            .unwrap(),
+        [root]
+    );
+    // The content scrolled into view at the bottom, but the part of it below
+    // the scroll container stays hidden.
+    assert_eq!(
+        tree.hit_test(root, point(25.0, 105.0)).unwrap(),
+        [content, scroller, root]
+    );
+    assert_eq!(tree.hit_test(root, point(25.0, 115.0)).unwrap(), [root]);
+}
+
+#[test]
+fn deep_trees_do_not_overflow_the_stack() {
+    let tree = TaffyTree::new();
+    let mut node = tree.new_leaf(Arc::new(Style::new())).unwrap();
+    for _ in 0..100_000 {
+        node = tree
+            .new_with_children(Arc::new(Style::new()), vec![node])
+            .unwrap();
+    }
+
+    assert_eq!(tree.hit_test(node, point(0.0, 0.0)).unwrap(), []);
+    assert_eq!(
+        tree.hit_test_rect(node, rect(0.0, 0.0, 1.0, 1.0)).unwrap(),
+        []
+    );
+
+    // Each line is indented by its depth, so the debug tree is kept shallower.
+    let mut node = tree.new_leaf(Arc::new(Style::new())).unwrap();
+    for _ in 0..3_000 {
+        node = tree
+            .new_with_children(Arc::new(Style::new()), vec![node])
+            .unwrap();
+    }
+    assert_eq!(tree.debug_tree(node).unwrap().lines().count(), 3_002);
+}
+
+#[test]
+fn debug_tree_draws_the_hierarchy() {
+    let tree = TaffyTree::new();
+    let grandchild = node(&tree, "", vec![]);
+    let first = node(&tree, "", vec![grandchild]);
+    let second = node(&tree, "", vec![]);
+    let root = node(&tree, "", vec![first, second]);
+    tree.compute_layout(root, MAX_CONTENT).unwrap();
+
+    let debug_tree = tree.debug_tree(root).unwrap();
+    let lines: Vec<&str> = debug_tree.lines().collect();
+    let labels: Vec<&str> = lines
+        .iter()
+        .map(|line| line.split(" [").next().unwrap())
+        .collect();
+    assert_eq!(
+        labels,
+        [
+            "TREE",
+            "└──  FLEX ROW",
+            "    ├──  FLEX ROW",
+            "    │   └──  LEAF",
+            "    └──  LEAF",
+        ]
+    );
+    for (line, node) in lines[1..].iter().zip([root, first, grandchild, second]) {
+        assert!(line.ends_with(&format!("({node:?})")), "{line}");
+    }
+}
+
+#[test]
+fn debug_nodes_list_the_hierarchy() {
+    let tree = TaffyTree::new();
+    let grandchild = node(&tree, "width: 10px; height: 5px", vec![]);
+    let first = node(&tree, "flex-direction: column", vec![grandchild]);
+    let second = node(&tree, "display: grid; width: 20px", vec![]);
+    let root = node(&tree, "", vec![first, second]);
+    tree.compute_layout(root, MAX_CONTENT).unwrap();
+
+    let nodes = tree.debug_nodes(root).unwrap();
+    let summary: Vec<_> = nodes
+        .iter()
+        .map(|debug| (debug.node, debug.depth, debug.label.as_str()))
+        .collect();
+    assert_eq!(
+        summary,
+        [
+            (root, 0, "FLEX ROW"),
+            (first, 1, "FLEX COL"),
+            (grandchild, 2, "LEAF"),
+            (second, 1, "LEAF"),
+        ]
+    );
+    for debug in &nodes {
+        let layout = tree.get_layout(debug.node).unwrap();
+        assert_eq!(debug.layout.location, layout.location);
+        assert_eq!(debug.layout.size, layout.size);
+    }
+    assert_eq!(nodes[1].layout.size.width, 10.0);
+    assert_eq!(nodes[3].layout.location.x, 10.0);
+    assert!(matches!(
+        nodes[2].style.size.width,
+        Dimension::Length { value } if value == 10.0
+    ));
+    assert!(matches!(nodes[3].style.display, Display::Grid));
+
+    tree.remove(grandchild).unwrap();
+    assert!(matches!(
+        tree.debug_nodes(grandchild),
+        Err(TaffyError::InvalidInputNode { .. })
+    ));
+}
@@ -0,0 +1,207 @@
+// Panics inside a layout pass are contained and leave the tree usable, and
+// recover() brings a tree back after a panic anywhere else poisoned it. Calls
+// back into a tree from its own layout pass fail instead of deadlocking.
+
+use std::{
+    panic,
+    sync::{Arc, Mutex},
+};
+
+use taffy::{
+    AvailableSpace, Display, GridPlacement, LineGridPlacement, MeasureError, MeasureFunction,
+    NodeContext, NodeId, SizeAvailableSpace, SizeFloat, SizeOptionFloat, Style, TaffyError,
+    TaffyTree,
+};
+
+const MAX_CONTENT: SizeAvailableSpace = SizeAvailableSpace {
+    width: AvailableSpace::MaxContent,
+    height: AvailableSpace::MaxContent,
+};
+
+struct Panicking;
+
+impl MeasureFunction for Panicking {
+    fn measure(
+        &self,
+        _known_size: SizeOptionFloat,
+        _avaliable_space: SizeAvailableSpace,
+        _node: NodeId,
+        _context: Option<NodeContext>,
+    ) -> Result<SizeFloat, MeasureError> {
+        panic!("measure function bug")
+    }
+}
+
+// Measures nothing, but panics when the tree drops it while holding its lock.
+struct PanicsOnDrop;
+
+impl MeasureFunction for PanicsOnDrop {
+    fn measure(
+        &self,
+        _known_size: SizeOptionFloat,
+        _avaliable_space: SizeAvailableSpace,
+        _node: NodeId,
+        _context: Option<NodeContext>,
+    ) -> Result<SizeFloat, MeasureError> {
+        Ok(SizeFloat {
+            width: 0.0,
+            height: 0.0,
+        })
+    }
+}
+
+impl Drop for PanicsOnDrop {
+    fn drop(&mut self) {
+        panic!("drop bug")
+    }
+}
+
+// Reads the tree it measures for, and keeps the errors that returned.
+struct Reentrant {
+    tree: Arc<TaffyTree>,
+    errors: Arc<Mutex<Vec<TaffyError>>>,
+}
+
+impl MeasureFunction for Reentrant {
+    fn measure(
+        &self,
+        _known_size: SizeOptionFloat,
+        _avaliable_space: SizeAvailableSpace,
+        node: NodeId,
+        _context: Option<NodeContext>,
+    ) -> Result<SizeFloat, MeasureError> {
+        if let Err(error) = self.tree.get_children(node) {
+            self.errors.lock().unwrap().push(error);
+        }
+        Ok(SizeFloat {
+            width: 10.0,
+            height: 10.0,
+        })
+    }
+}
+
+fn leaf_in_root(tree: &TaffyTree) -> (NodeId, NodeId) {
+    let leaf = tree.new_leaf(Arc::new(Style::new())).unwrap();
+    let root = tree
+        .new_with_children(Arc::new(Style::new()), vec![leaf])
+        .unwrap();
+    (leaf, root)
+}
+
+#[test]
+fn measure_function_panics_are_measure_failures() {
+    let tree = TaffyTree::new();
+    let (leaf, root) = leaf_in_root(&tree);
+    tree.set_measure_function(leaf, Box::new(Panicking))
+        .unwrap();
+
+    match tree.compute_layout(root, MAX_CONTENT) {
+        Err(TaffyError::MeasureFailed { node_id, reason }) => {
+            assert_eq!(node_id, leaf);
+            assert_eq!(reason, "panicked: measure function bug");
+        }
+        result => panic!("unexpected result {result:?}"),
+    }
+    assert!(tree.is_dirty(root).unwrap());
+
+    tree.recover().unwrap();
+    tree.clear_measure_function(leaf).unwrap();
+    tree.compute_layout(root, MAX_CONTENT).unwrap();
+    assert!(!tree.is_dirty(root).unwrap());
+}
+
+#[test]
+fn panics_in_the_measure_argument_are_measure_failures() {
+    let tree = TaffyTree::new();
+    let (leaf, root) = leaf_in_root(&tree);
+
+    match tree.compute_layout_with_measure(root, MAX_CONTENT, Box::new(Panicking)) {
+        Err(TaffyError::MeasureFailed { node_id, reason }) => {
+            assert_eq!(node_id, leaf);
+            assert_eq!(reason, "panicked: measure function bug");
+        }
+        result => panic!("unexpected result {result:?}"),
+    }
+    tree.recover().unwrap();
+    tree.compute_layout(root, MAX_CONTENT).unwrap();
+}
+
+#[test]
+fn recover_clears_a_poisoned_lock() {
+    let tree = TaffyTree::new();
+    let (leaf, root) = leaf_in_root(&tree);
+    tree.set_measure_function(leaf, Box::new(PanicsOnDrop))
+        .unwrap();
+
+    let result = panic::catch_unwind(|| tree.clear_measure_function(leaf));
+    assert!(result.is_err());
+    assert!(matches!(tree.get_layout(root), Err(TaffyError::Poison)));
+    assert!(matches!(
+        tree.compute_layout(root, MAX_CONTENT),
+        Err(TaffyError::Poison)
+    ));
+
+    tree.recover().unwrap();
+    assert!(tree.is_dirty(root).unwrap());
+    assert_eq!(tree.get_children(root).unwrap(), vec![leaf]);
+    tree.compute_layout(root, MAX_CONTENT).unwrap();
+    assert!(!tree.is_dirty(root).unwrap());
+}
+
+#[test]
+fn measure_functions_cannot_reenter_their_tree() {
+    let tree = Arc::new(TaffyTree::new());
+    let (leaf, root) = leaf_in_root(&tree);
+    let errors = Arc::new(Mutex::new(Vec::new()));
+    let measure = Reentrant {
+        tree: tree.clone(),
+        errors: errors.clone(),
+    };
+
+    tree.compute_layout_with_measure(root, MAX_CONTENT, Box::new(measure))
+        .unwrap();
+    let errors = errors.lock().unwrap();
+    assert!(!errors.is_empty());
+    assert!(errors
+        .iter()
+        .all(|error| matches!(error, TaffyError::LockContention)));
+    assert_eq!(tree.get_layout(leaf).unwrap().size.width, 10.0);
+
+    // The guard only lasts as long as the layout pass.
+    assert_eq!(tree.get_children(leaf).unwrap(), vec![]);
+    tree.mark_dirty(leaf).unwrap();
+    tree.compute_layout(root, MAX_CONTENT).unwrap();
+    assert_eq!(tree.get_layout(leaf).unwrap().size.width, 0.0);
+}
+
+// taffy 0.4 overflows placing an item in the implicit track before the
+// explicit grid, which is a panic in builds with overflow checks.
+#[test]
+fn grid_item_before_the_explicit_grid() {
+    let tree = TaffyTree::new();
+    let style = Style::new();
+    style
+        .set_grid_column(LineGridPlacement {
+            start: GridPlacement::Auto,
+            end: GridPlacement::Line { index: 1 },
+        })
+        .unwrap();
+    let item = tree.new_leaf(Arc::new(style)).unwrap();
+    let grid = Style::new();
+    grid.set_display(Display::Grid).unwrap();
+    let root = tree.new_with_children(Arc::new(grid), vec![item]).unwrap();
+
+    match tree.compute_layout(root, MAX_CONTENT) {
+        Err(TaffyError::Internal { reason }) if cfg!(debug_assertions) => {
+            assert!(reason.starts_with("taffy panicked: "), "{reason}")
+        }
+        Ok(()) if !cfg!(debug_assertions) => {}
+        result => panic!("unexpected result {result:?}"),
+    }
+    assert!(tree.is_dirty(root).unwrap());
+
+    tree.recover().unwrap();
+    tree.set_style(item, Arc::new(Style::new())).unwrap();
+    tree.compute_layout(root, MAX_CONTENT).unwrap();
+    assert!(!tree.is_dirty(root).unwrap());
+}
@@ -0,0 +1,194 @@
+#![cfg(feature = "text")]
+
+// Measurements with Ahem, whose glyphs (spaces included) are all exactly one
+// em wide, so every width below is a number of characters times the font size.
+
+use std::sync::Arc;
+
+use taffy::{
+    AvailableSpace, FontCollection, SizeAvailableSpace, SizeFloat, SizeOptionFloat, Style,
+    TaffyError, TaffyTree, TextNode, TextStyle, TextWrap,
+};
+
+const AHEM: &[u8] = include_bytes!("fonts/ahem.ttf");
+
+const MAX_CONTENT: SizeAvailableSpace = SizeAvailableSpace {
+    width: AvailableSpace::MaxContent,
+    height: AvailableSpace::MaxContent,
+};
+
+const UNKNOWN: SizeOptionFloat = SizeOptionFloat {
+    width: None,
+    height: None,
+};
+
+fn text_node(text: &str, wrap: TextWrap) -> TextNode {
+    let fonts = Arc::new(FontCollection::new());
+    let font = fonts.load_font(AHEM.to_vec()).unwrap();
+    let style = TextStyle {
+        font,
+        font_size: 10.0,
+        line_height: 1.5,
+        wrap,
+    };
+    TextNode::new(fonts, text.into(), style).unwrap()
+}
+
+fn measure(node: &TextNode, width: AvailableSpace) -> SizeFloat {
+    let available_space = SizeAvailableSpace {
+        width,
+        height: AvailableSpace::MaxContent,
+    };
+    node.measure(UNKNOWN, available_space).unwrap()
+}
+
+fn size(width: f32, height: f32) -> SizeFloat {
+    SizeFloat { width, height }
+}
+
+#[test]
+fn single_line() {
+    let node = text_node("Hello", TextWrap::Word);
+
+    assert_eq!(measure(&node, AvailableSpace::MaxContent), size(50.0, 15.0));
+    assert_eq!(
+        measure(&node, AvailableSpace::Definite { value: 200.0 }),
+        size(50.0, 15.0)
+    );
+}
+
+#[test]
+fn wraps_at_the_available_width() {
+    let node = text_node("HH HH HHH", TextWrap::Word);
+
+    // The spaces at the ends of lines don't count towards their width.
+    assert_eq!(
+        measure(&node, AvailableSpace::Definite { value: 55.0 }),
+        size(50.0, 30.0)
+    );
+    assert_eq!(
+        measure(&node, AvailableSpace::Definite { value: 35.0 }),
+        size(30.0, 45.0)
+    );
+}
+
+#[test]
+fn forced_line_breaks() {
+    let node = text_node("HHHH\nHH", TextWrap::NoWrap);
+
+    assert_eq!(measure(&node, AvailableSpace::MaxContent), size(40.0, 30.0));
+    assert_eq!(measure(&node, AvailableSpace::MinContent), size(40.0, 30.0));
+}
+
+#[test]
+fn min_and_max_content() {
+    let node = text_node("HH HHH H", TextWrap::Word);
+
+    assert_eq!(measure(&node, AvailableSpace::MinContent), size(30.0, 45.0));
+    assert_eq!(measure(&node, AvailableSpace::MaxContent), size(80.0, 15.0));
+
+    node.set_style(TextStyle {
+        wrap: TextWrap::NoWrap,
+        ..node.get_style().unwrap()
+    })
+    .unwrap();
+    assert_eq!(measure(&node, AvailableSpace::MinContent), size(80.0, 15.0));
+}
+
+#[test]
+fn known_sizes_are_kept() {
+    let node = text_node("HH HH", TextWrap::Word);
+    let known_size = SizeOptionFloat {
+        width: Some(20.0),
+        height: None,
+    };
+    let available_space = SizeAvailableSpace {
+        width: AvailableSpace::MaxContent,
+        height: AvailableSpace::MaxContent,
+    };
+
+    assert_eq!(
+        node.measure(known_size, available_space).unwrap(),
+        size(20.0, 30.0)
+    );
+}
+
+#[test]
+fn lays_out_attached_nodes() {
+    let text = Arc::new(text_node("HH HH", TextWrap::Word));
+    let tree = Arc::new(TaffyTree::new());
+    let leaf = tree.new_leaf(Arc::new(Style::new())).unwrap();
+    let root = tree
+        .new_with_children(
+            Arc::new(Style::from_css("display: flex; width: 30px".into()).unwrap()),
+            vec![leaf],
+        )
+        .unwrap();
+    text.attach(tree.clone(), leaf).unwrap();
+
+    tree.compute_layout(root, MAX_CONTENT).unwrap();
+    assert_eq!(tree.get_layout(leaf).unwrap().size, size(30.0, 30.0));
+}
+
+#[test]
+fn changes_relayout_attached_nodes() {
+    let text = Arc::new(text_node("HH", TextWrap::Word));
+    let tree = Arc::new(TaffyTree::new());
+    let leaf = tree.new_leaf(Arc::new(Style::new())).unwrap();
+    let root = tree
+        .new_with_children(
+            Arc::new(Style::from_css("display: flex".into()).unwrap()),
+            vec![leaf],
+        )
+        .unwrap();
+    text.clone().attach(tree.clone(), leaf).unwrap();
+    tree.compute_layout(root, MAX_CONTENT).unwrap();
+    assert_eq!(tree.get_layout(leaf).unwrap().size, size(20.0, 15.0));
+
+    text.set_text("HHHH".into()).unwrap();
+    assert!(tree.is_dirty(root).unwrap());
+    tree.compute_layout(root, MAX_CONTENT).unwrap();
+    assert_eq!(tree.get_layout(leaf).unwrap().size, size(40.0, 15.0));
+
+    text.set_style(TextStyle {
+        font_size: 20.0,
+        ..text.get_style().unwrap()
+    })
+    .unwrap();
+    assert!(tree.is_dirty(root).unwrap());
+    tree.compute_layout(root, MAX_CONTENT).unwrap();
+    assert_eq!(tree.get_layout(leaf).unwrap().size, size(80.0, 30.0));
+}
+
+#[test]
+fn rejects_bad_fonts() {
+    let fonts = Arc::new(FontCollection::new());
+
+    assert!(matches!(
+        fonts.load_font(b"not a font".to_vec()),
+        Err(TaffyError::InvalidFontData)
+    ));
+    assert!(matches!(
+        fonts.load_font(AHEM[..100].to_vec()),
+        Err(TaffyError::InvalidFontData)
+    ));
+    assert_eq!(fonts.get_font_count().unwrap(), 0);
+
+    let style = TextStyle {
+        font: 0,
+        font_size: 10.0,
+        line_height: 1.0,
+        wrap: TextWrap::Word,
+    };
+    assert!(matches!(
+        TextNode::new(fonts.clone(), "H".into(), style.clone()),
+        Err(TaffyError::InvalidFont { font: 0 })
+    ));
+
+    fonts.load_font(AHEM.to_vec()).unwrap();
+    let node = TextNode::new(fonts, "H".into(), style.clone()).unwrap();
+    assert!(matches!(
+        node.set_style(TextStyle { font: 1, ..style }),
+        Err(TaffyError::InvalidFont { font: 1 })
+    ));
+}
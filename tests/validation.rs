@@ -0,0 +1,107 @@
+use std::sync::Arc;
+
+use taffy::{
+    Dimension, LengthPercentage, SizeDimension, Style, StyleData, StyleValidation, TaffyError,
+    TaffyTree,
+};
+
+fn assert_invalid<T>(result: Result<T, TaffyError>, expected: &str) {
+    match result {
+        Err(TaffyError::InvalidStyleValue { property, .. }) => assert_eq!(property, expected),
+        Err(error) => panic!("expected {expected} to be invalid, got {error}"),
+        Ok(_) => panic!("expected {expected} to be invalid"),
+    }
+}
+
+fn nan_flex_grow() -> StyleData {
+    let mut data = Style::new().to_data().unwrap();
+    data.flex_grow = f32::NAN;
+    data
+}
+
+fn lenient_style() -> Style {
+    let style = Style::new();
+    style.set_validation(StyleValidation::Lenient).unwrap();
+    style
+}
+
+#[test]
+fn strict_mode_rejects_invalid_values() {
+    let style = Style::new();
+    assert_eq!(style.get_validation().unwrap(), StyleValidation::Strict);
+    assert_invalid(style.set_flex_grow(f32::NAN), "flex-grow");
+    assert_invalid(style.set_flex_shrink(-1.0), "flex-shrink");
+    assert_invalid(style.set_aspect_ratio(Some(0.0)), "aspect-ratio");
+    assert_invalid(style.set_scrollbar_width(f32::INFINITY), "scrollbar-width");
+    assert_invalid(
+        style.set_size(SizeDimension {
+            width: Dimension::Length { value: 10.0 },
+            height: Dimension::Percent { value: -0.5 },
+        }),
+        "height",
+    );
+    assert_invalid(Style::from_css("padding-left: -1px".into()), "padding-left");
+    assert_invalid(Style::from_data(nan_flex_grow()), "flex-grow");
+    assert_eq!(style.get_flex_grow().unwrap(), 0.0);
+
+    // Margins and insets can be negative.
+    Style::from_css("margin: -10px; left: -5%".into()).unwrap();
+}
+
+#[test]
+fn lenient_mode_clamps_invalid_values() {
+    let style = lenient_style();
+    style.set_flex_grow(f32::NAN).unwrap();
+    assert_eq!(style.get_flex_grow().unwrap(), 0.0);
+    style.set_flex_shrink(-1.0).unwrap();
+    assert_eq!(style.get_flex_shrink().unwrap(), 0.0);
+    style.set_scrollbar_width(f32::INFINITY).unwrap();
+    assert_eq!(style.get_scrollbar_width().unwrap(), f32::MAX);
+    style.set_aspect_ratio(Some(-2.0)).unwrap();
+    assert_eq!(style.get_aspect_ratio().unwrap(), None);
+    style.apply_css("padding-left: -1px".into()).unwrap();
+    assert!(matches!(
+        style.get_padding().unwrap().left,
+        LengthPercentage::Length { value } if value == 0.0
+    ));
+
+    // Styles are strict until they're told otherwise, whatever other styles
+    // do.
+    assert_invalid(Style::new().set_flex_grow(f32::NAN), "flex-grow");
+}
+
+#[test]
+fn trees_validate_styles() {
+    let tree = TaffyTree::new();
+    assert_eq!(
+        tree.get_style_validation().unwrap(),
+        StyleValidation::Strict
+    );
+    let node = tree.new_leaf(Arc::new(Style::new())).unwrap();
+
+    // Only a style converted from taffy's own can still hold an invalid value.
+    let invalid = || {
+        Arc::new(Style::from(taffy_core::Style {
+            flex_grow: f32::NAN,
+            ..Default::default()
+        }))
+    };
+    assert_invalid(tree.set_style(node, invalid()), "flex-grow");
+    assert_invalid(tree.new_leaf(invalid()), "flex-grow");
+    assert_invalid(tree.set_style_data(node, nan_flex_grow()), "flex-grow");
+    assert_eq!(tree.get_total_node_count().unwrap(), 1);
+}
+
+#[test]
+fn lenient_trees_clamp_invalid_values() {
+    let tree = TaffyTree::new();
+    tree.set_style_validation(StyleValidation::Lenient).unwrap();
+    let node = tree.new_leaf(Arc::new(Style::new())).unwrap();
+    tree.set_style_data(node, nan_flex_grow()).unwrap();
+    assert_eq!(tree.get_style_data(node).unwrap().flex_grow, 0.0);
+
+    // Another tree keeps its own mode.
+    let strict = TaffyTree::new();
+    let other = strict.new_leaf(Arc::new(Style::new())).unwrap();
+    assert_invalid(strict.set_style_data(other, nan_flex_grow()), "flex-grow");
+}